@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+
+/// Value given as the answer of a part, `None` when the solution could not find one.
+pub trait Answer: Debug {
+    fn answer(&self) -> Option<String>;
+}
+
+macro_rules! impl_answer_with_to_string {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn answer(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_with_to_string!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+impl_answer_with_to_string!(num_bigint::BigUint, num_bigint::BigInt);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(&self) -> Option<String> {
+        self.as_ref().and_then(Answer::answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_give_answer_without_wrapper() {
+        assert_eq!(42u32.answer(), Some(String::from("42")));
+        assert_eq!((-7i64).answer(), Some(String::from("-7")));
+        assert_eq!(Some(12521u32).answer(), Some(String::from("12521")));
+        assert_eq!(None::<u32>.answer(), None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{read_input, Answer, Solution};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: dayNN [<INPUT>] [--part <1|2>]
+
+INPUT defaults to input1.txt, use - to read the input from stdin.
+Without --part both parts are run.";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CliArgs {
+    pub input: PathBuf,
+    pub part: Option<u8>,
+}
+
+pub fn parse_cli_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut input: Option<PathBuf> = None;
+    let mut part: Option<u8> = None;
+    let mut args_it = args.iter();
+    while let Some(arg) = args_it.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args_it
+                    .next()
+                    .ok_or(format!("Missing value for {}\n\n{}", arg, USAGE))?;
+                part = Some(parse_part(value)?);
+            }
+            "-h" | "--help" => return Err(String::from(USAGE)),
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown argument {}\n\n{}", arg, USAGE))
+            }
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+    return Ok(CliArgs {
+        input: input.unwrap_or_else(|| PathBuf::from("input1.txt")),
+        part,
+    });
+}
+
+pub fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err(format!("Part should be 1 or 2, found {:?}", value)),
+    }
+}
+
+/// Extra subcommand of a day binary, run as `dayNN <NAME> [<INPUT>] [--part <1|2>]` and printing
+/// what `run` returns for the parsed input.
+pub struct Command<S: Solution> {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub run: fn(&S::Input, &CliArgs) -> Result<String, String>,
+}
+
+/// Entry point shared by every day binary: reads the input named on the command line and
+/// prints the requested parts.
+pub fn run_cli<S: Solution>() -> ExitCode {
+    return run_cli_with::<S>(&[]);
+}
+
+/// Same as [`run_cli`], with day specific subcommands.
+pub fn run_cli_with<S: Solution>(commands: &[Command<S>]) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = args
+        .first()
+        .and_then(|name| commands.iter().find(|command| command.name == name));
+    let result = match command {
+        Some(command) => parse_cli_args(&args[1..]).and_then(|args| {
+            let data = read_and_parse::<S>(&args)?;
+            println!("{}", (command.run)(&data, &args)?);
+            return Ok(());
+        }),
+        None => parse_cli_args(&args).and_then(run::<S>),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) if message == USAGE && !commands.is_empty() => {
+            eprintln!("{}\n\nCommands:", message);
+            for command in commands {
+                eprintln!("  {:<10} {}", command.name, command.help);
+            }
+            ExitCode::FAILURE
+        }
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn read_and_parse<S: Solution>(args: &CliArgs) -> Result<S::Input, String> {
+    let input = read_input(&args.input)
+        .map_err(|e| format!("An error occurred when reading {:?}: {}", args.input, e))?;
+    return S::parse_data(input)
+        .map_err(|e| format!("An error occurred when parsing {:?}: {}", args.input, e));
+}
+
+fn run<S: Solution>(args: CliArgs) -> Result<(), String> {
+    let data = read_and_parse::<S>(&args)?;
+
+    if args.part != Some(2) {
+        println!("{}", describe(1, S::part1(&data)));
+    }
+    if args.part.is_none() {
+        println!("--------------------------------------------------");
+    }
+    if args.part != Some(1) {
+        println!("{}", describe(2, S::part2(&data)));
+    }
+    return Ok(());
+}
+
+fn describe<T: Answer>(part: u8, result: Result<T, String>) -> String {
+    match result.map(|output| output.answer()) {
+        // Drawings such as day 13's folded page start on their own line.
+        Ok(Some(answer)) if answer.contains('\n') => format!("Part {}:\n{}", part, answer),
+        Ok(Some(answer)) => format!("Part {}: {}", part, answer),
+        Ok(None) => format!("Part {}: no answer", part),
+        Err(message) => format!("Part {}: error: {}", part, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn it_should_parse_cli_args_correctly() {
+        let expected = CliArgs {
+            input: PathBuf::from("-"),
+            part: Some(2),
+        };
+        assert_eq!(parse_cli_args(&args("- --part 2")).unwrap(), expected);
+        let expected = CliArgs {
+            input: PathBuf::from("input1.txt"),
+            part: None,
+        };
+        assert_eq!(parse_cli_args(&args("")).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_cli_args() {
+        assert!(parse_cli_args(&args("--part 3")).is_err());
+        assert!(parse_cli_args(&args("--part")).is_err());
+        assert!(parse_cli_args(&args("a.txt b.txt")).is_err());
+        assert!(parse_cli_args(&args("--verbose")).is_err());
+    }
+}
@@ -0,0 +1,423 @@
+use crate::parse::{non_empty_lines, InputLine, ParseError};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+const ALL_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular 2D storage addressed by `(x, y)`, `x` being the column and `y` the row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let height = rows.len();
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "row {} has {} cells, expected {}",
+                y,
+                rows[y].len(),
+                width
+            ));
+        }
+        return Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        });
+    }
+
+    /// One row per non-empty line of `input`, each character turned into a cell by `f`.
+    pub fn parse<F: Fn(char) -> Option<T>>(
+        input: &str,
+        expected: &str,
+        f: F,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_lines(non_empty_lines(input), expected, f)
+    }
+
+    /// Same as `parse` for lines already split out of a bigger input.
+    pub fn parse_lines<'a, I, F>(lines: I, expected: &str, f: F) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = InputLine<'a>>,
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width: Option<usize> = None;
+        let mut cells: Vec<T> = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            let text = line.trimmed();
+            let mut row_width = 0;
+            for (i, c) in text.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| line.unexpected(&text[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            match width {
+                Some(expected) if expected != row_width => {
+                    return Err(ParseError::WrongLength {
+                        line: line.number,
+                        expected,
+                        found: row_width,
+                    });
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        return Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        });
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Same as `get` but accepts coordinates falling outside the grid on any side.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        self.get(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    fn positions_around<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| {
+                Some((x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?))
+            })
+            .filter(move |(ax, ay)| *ax < width && *ay < height)
+    }
+
+    /// Positions of the up to 4 cells sharing an edge with `(x, y)`.
+    pub fn adjacents(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.positions_around(x, y, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// Positions of the up to 8 cells touching `(x, y)`, diagonals included.
+    pub fn adjacents_with_diagonals(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.positions_around(x, y, &ALL_OFFSETS)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Copy of the `width`x`height` area starting at `(x, y)`, clamped to the grid bounds.
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = width.min(self.width.saturating_sub(x));
+        let height = height.min(self.height.saturating_sub(y));
+        Grid::from_fn(width, height, |i, j| self[(x + i, y + j)].clone())
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Mirror along the vertical axis: columns are reversed, each row is read right to left.
+    pub fn mirror_x(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirror along the horizontal axis: rows are reversed, the bottom row comes first.
+    pub fn mirror_y(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Repeat the grid `x_count` times horizontally and `y_count` times vertically,
+    /// letting `f` derive each copied cell from the original one and its tile coordinates.
+    pub fn tile<F: Fn(&T, usize, usize) -> T>(
+        &self,
+        x_count: usize,
+        y_count: usize,
+        f: F,
+    ) -> Grid<T> {
+        Grid::from_fn(self.width * x_count, self.height * y_count, |x, y| {
+            f(
+                &self[(x % self.width, y % self.height)],
+                x / self.width,
+                y / self.height,
+            )
+        })
+    }
+
+    pub fn render<F: Fn(&T) -> String>(&self, f: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|cell| cell.to_string()))
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = String;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Grid::from_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn it_should_build_from_rows() {
+        let grid = sample_grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&5));
+    }
+
+    #[test]
+    fn it_should_parse() {
+        let grid = Grid::parse("123\n456\n", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid, sample_grid());
+        let expected = ParseError::UnexpectedToken {
+            line: 2,
+            column: 2,
+            text: String::from("x"),
+            expected: String::from("a digit"),
+        };
+        assert_eq!(
+            Grid::parse("123\n4x6", "a digit", |c| c.to_digit(10)),
+            Err(expected)
+        );
+        let expected = ParseError::WrongLength {
+            line: 3,
+            expected: 3,
+            found: 2,
+        };
+        assert_eq!(
+            Grid::parse("123\n\n45", "a digit", |c| c.to_digit(10)),
+            Err(expected)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_ragged_rows() {
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn it_should_iterate_adjacents() {
+        let grid = sample_grid();
+        assert_eq!(
+            grid.adjacents(1, 0).collect::<Vec<_>>(),
+            vec![(0, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(
+            grid.adjacents_with_diagonals(0, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (1, 1)]
+        );
+        assert_eq!(grid.adjacents_with_diagonals(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn it_should_give_rows_and_columns() {
+        let grid = sample_grid();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn it_should_give_empty_columns_outside_the_grid() {
+        let grid = sample_grid();
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.column(7).count(), 0);
+    }
+
+    #[test]
+    fn it_should_transpose() {
+        let expected = Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+        assert_eq!(sample_grid().transpose(), expected);
+    }
+
+    #[test]
+    fn it_should_mirror() {
+        let expected = Grid::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]).unwrap();
+        assert_eq!(sample_grid().mirror_x(), expected);
+        let expected = Grid::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]).unwrap();
+        assert_eq!(sample_grid().mirror_y(), expected);
+    }
+
+    #[test]
+    fn it_should_cut_sub_grid() {
+        let expected = Grid::from_rows(vec![vec![5, 6]]).unwrap();
+        assert_eq!(sample_grid().sub_grid(1, 1, 5, 5), expected);
+    }
+
+    #[test]
+    fn it_should_tile() {
+        let grid = Grid::from_rows(vec![vec![8, 9]]).unwrap();
+        let tiled = grid.tile(2, 2, |v, tx, ty| (*v + tx as u32 + ty as u32 - 1) % 9 + 1);
+        let expected = Grid::from_rows(vec![vec![8, 9, 9, 1], vec![9, 1, 1, 2]]).unwrap();
+        assert_eq!(tiled, expected);
+    }
+
+    #[test]
+    fn it_should_render() {
+        assert_eq!(sample_grid().to_string(), "123\n456");
+        assert_eq!(
+            sample_grid().render(|v| String::from(if *v % 2 == 0 { "#" } else { "." })),
+            ".#.\n#.#"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+/// Content of `file_name`, or of stdin when `file_name` is `-`.
+pub fn read_input<P: AsRef<Path>>(file_name: P) -> std::io::Result<String> {
+    let mut contents = String::new();
+    if file_name.as_ref() == Path::new("-") {
+        std::io::stdin().read_to_string(&mut contents)?;
+    } else {
+        File::open(file_name)?.read_to_string(&mut contents)?;
+    }
+    return Ok(contents);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_read_file_content() {
+        let path = std::env::temp_dir().join("aoc_common_read_input.txt");
+        std::fs::write(&path, "1\n2\n3\n").unwrap();
+        assert_eq!(read_input(&path).unwrap(), "1\n2\n3\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_should_fail_on_missing_file() {
+        assert!(read_input("this/file/does/not/exist.txt").is_err());
+    }
+}
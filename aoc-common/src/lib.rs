@@ -0,0 +1,15 @@
+mod answer;
+mod cli;
+mod grid;
+mod input;
+mod matrix;
+mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use cli::{parse_cli_args, parse_part, run_cli, run_cli_with, CliArgs, Command};
+pub use grid::Grid;
+pub use input::read_input;
+pub use matrix::convert_to_column_matrix;
+pub use parse::{input_lines, missing_at_end, non_empty_lines, InputLine, ParseError};
+pub use solution::Solution;
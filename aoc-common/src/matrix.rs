@@ -0,0 +1,26 @@
+pub fn convert_to_column_matrix<T: Clone>(
+    matrix: &Vec<Vec<T>>,
+    column_count: usize,
+) -> Vec<Vec<T>> {
+    (0..column_count)
+        .map(|i| matrix.iter().map(|row| row[i].clone()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_matrix() -> Vec<Vec<u32>> {
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]
+    }
+
+    #[test]
+    fn it_should_convert_to_column_matrix() {
+        let expected = vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]];
+        assert_eq!(convert_to_column_matrix(&sample_matrix(), 3), expected);
+        let rectangle = vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']];
+        let expected = vec![vec!['a', 'c', 'e'], vec!['b', 'd', 'f']];
+        assert_eq!(convert_to_column_matrix(&rectangle, 2), expected);
+    }
+}
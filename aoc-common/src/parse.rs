@@ -0,0 +1,239 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Why an input could not be parsed, lines and columns starting at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    UnexpectedToken {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    MissingToken {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+    WrongLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidNumber { line, column, text } => {
+                write!(
+                    f,
+                    "line {}, column {}: {:?} is not a valid number",
+                    line, column, text
+                )
+            }
+            ParseError::UnexpectedToken {
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found {:?}",
+                line, column, expected, text
+            ),
+            ParseError::MissingToken {
+                line,
+                column,
+                expected,
+            } => write!(f, "line {}, column {}: missing {}", line, column, expected),
+            ParseError::WrongLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} items, found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+/// One line of a puzzle input, remembering its position to build diagnostics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Every line of `input`, numbered from 1, empty ones included.
+pub fn input_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input.split('\n').enumerate().map(|(i, text)| InputLine {
+        number: i + 1,
+        text,
+    })
+}
+
+/// Lines of `input` holding something else than whitespace.
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input_lines(input).filter(|line| !line.is_empty())
+}
+
+impl<'a> InputLine<'a> {
+    pub fn trimmed(&self) -> &'a str {
+        self.text.trim()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trimmed().is_empty()
+    }
+
+    /// Column of `token` when it is a slice of this line, the line start otherwise.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let position = token.as_ptr() as usize;
+        if position >= start && position <= start + self.text.len() {
+            return self.text[..position - start].chars().count() + 1;
+        }
+        return 1;
+    }
+
+    pub fn parse_number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .trim()
+            .parse::<T>()
+            .map_err(|_| ParseError::InvalidNumber {
+                line: self.number,
+                column: self.column_of(token),
+                text: String::from(token),
+            })
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(separator)
+            .ok_or_else(|| self.unexpected(text, &format!("{:?} separator", separator)))
+    }
+
+    pub fn unexpected(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::UnexpectedToken {
+            line: self.number,
+            column: self.column_of(token),
+            text: String::from(token),
+            expected: String::from(expected),
+        }
+    }
+
+    /// Fails on the first character of `text` rejected by `is_valid`, `text` being a slice of
+    /// this line.
+    pub fn check_chars<F: Fn(char) -> bool>(
+        &self,
+        text: &'a str,
+        is_valid: F,
+        expected: &str,
+    ) -> Result<(), ParseError> {
+        match text.char_indices().find(|(_, c)| !is_valid(*c)) {
+            Some((i, c)) => Err(self.unexpected(&text[i..i + c.len_utf8()], expected)),
+            None => Ok(()),
+        }
+    }
+
+    /// Error for something which should have followed the end of the line.
+    pub fn missing(&self, expected: &str) -> ParseError {
+        ParseError::MissingToken {
+            line: self.number,
+            column: self.text.trim_end().chars().count() + 1,
+            expected: String::from(expected),
+        }
+    }
+}
+
+/// Error for an input which ends before `expected` was found.
+pub fn missing_at_end(input: &str, expected: &str) -> ParseError {
+    let last = input_lines(input.trim_end()).last().unwrap_or(InputLine {
+        number: 1,
+        text: "",
+    });
+    return last.missing(expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_number_lines_from_one() {
+        let lines: Vec<(usize, &str)> = input_lines("a\n\nb")
+            .map(|line| (line.number, line.text))
+            .collect();
+        assert_eq!(lines, vec![(1, "a"), (2, ""), (3, "b")]);
+        let lines: Vec<usize> = non_empty_lines("a\n  \nb\n").map(|l| l.number).collect();
+        assert_eq!(lines, vec![1, 3]);
+    }
+
+    #[test]
+    fn it_should_locate_invalid_number() {
+        let line = non_empty_lines("12\n  forward x5").nth(1).unwrap();
+        let (_, value) = line.split_once(line.trimmed(), " ").unwrap();
+        let expected = ParseError::InvalidNumber {
+            line: 2,
+            column: 11,
+            text: String::from("x5"),
+        };
+        assert_eq!(line.parse_number::<u32>(value), Err(expected));
+        assert_eq!(
+            line.parse_number::<u32>(value).unwrap_err().to_string(),
+            "line 2, column 11: \"x5\" is not a valid number"
+        );
+    }
+
+    #[test]
+    fn it_should_report_missing_separator() {
+        let line = non_empty_lines("0,9 -> 5,9").next().unwrap();
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 1,
+            text: String::from("0,9 -> 5,9"),
+            expected: String::from("\" | \" separator"),
+        };
+        assert_eq!(line.split_once(line.text, " | "), Err(expected));
+        let line = non_empty_lines("ab\n  aé¤b").nth(1).unwrap();
+        let expected = ParseError::UnexpectedToken {
+            line: 2,
+            column: 4,
+            text: String::from("é"),
+            expected: String::from("a or b"),
+        };
+        let is_a_or_b = |c: char| c == 'a' || c == 'b';
+        assert_eq!(
+            line.check_chars(line.trimmed(), is_a_or_b, "a or b"),
+            Err(expected)
+        );
+        assert_eq!(line.check_chars("ab", is_a_or_b, "a or b"), Ok(()));
+        assert_eq!(
+            missing_at_end("abc\ndef\n\n", "a blank line"),
+            ParseError::MissingToken {
+                line: 2,
+                column: 4,
+                expected: String::from("a blank line")
+            }
+        );
+    }
+}
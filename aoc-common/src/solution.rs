@@ -0,0 +1,12 @@
+use crate::{Answer, ParseError};
+
+/// One day of the calendar: how its input is parsed and how both parts are solved.
+pub trait Solution {
+    type Input;
+    type Part1Output: Answer;
+    type Part2Output: Answer;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String>;
+}
@@ -0,0 +1,128 @@
+use crate::days::{PartResult, Status};
+use aoc_common::{non_empty_lines, ParseError};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Expected answers, keyed by day, part and input id (the hash printed by the runner).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    expected: HashMap<(u8, u8, String), String>,
+}
+
+impl Answers {
+    /// Reads lines such as `15 2 02d9c117d3c6da2a 2853`, ignoring blank lines and `#` comments.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut expected = HashMap::new();
+        for line in non_empty_lines(input) {
+            let text = line.trimmed();
+            if text.starts_with('#') {
+                continue;
+            }
+            let (day, rest) = line.split_once(text, " ")?;
+            let (part, rest) = line.split_once(rest.trim_start(), " ")?;
+            let (input_id, answer) = line.split_once(rest.trim_start(), " ")?;
+            let part_number = line.parse_number::<u8>(part)?;
+            if part_number != 1 && part_number != 2 {
+                return Err(line.unexpected(part, "part 1 or 2"));
+            }
+            expected.insert(
+                (
+                    line.parse_number::<u8>(day)?,
+                    part_number,
+                    String::from(input_id),
+                ),
+                String::from(answer.trim()),
+            );
+        }
+        return Ok(Answers { expected });
+    }
+
+    pub fn verdict(&self, day: u8, input_id: &str, result: &PartResult) -> Verdict {
+        let key = (day, result.part, String::from(input_id));
+        match self.expected.get(&key) {
+            None => Verdict::Unknown,
+            Some(expected) if result.status == Status::Ok && &result.answer == expected => {
+                Verdict::Pass
+            }
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(part: u8, status: Status, answer: &str) -> PartResult {
+        PartResult {
+            part,
+            status,
+            answer: String::from(answer),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn it_should_compare_results_with_expected_answers() {
+        let answers =
+            Answers::parse("# day part input answer\n1 1 abc 7\n\n1  2  abc  5\n").unwrap();
+        assert_eq!(
+            answers.verdict(1, "abc", &result(1, Status::Ok, "7")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verdict(1, "abc", &result(2, Status::Ok, "6")),
+            Verdict::Fail {
+                expected: String::from("5")
+            }
+        );
+        assert_eq!(
+            answers.verdict(1, "abc", &result(2, Status::Error, "5")),
+            Verdict::Fail {
+                expected: String::from("5")
+            }
+        );
+        assert_eq!(
+            answers.verdict(1, "def", &result(1, Status::Ok, "7")),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.verdict(2, "abc", &result(1, Status::Ok, "7")),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn it_should_report_malformed_answers() {
+        assert_eq!(
+            Answers::parse("1 1 abc 7\n1 3 abc 5\n"),
+            Err(ParseError::UnexpectedToken {
+                line: 2,
+                column: 3,
+                text: String::from("3"),
+                expected: String::from("part 1 or 2"),
+            })
+        );
+        assert!(Answers::parse("1 1 abc\n").is_err());
+        assert!(Answers::parse("x 1 abc 7\n").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_common::{non_empty_lines, ParseError};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timings {
+    pub phase: Phase,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(phase: Phase, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        return Timings {
+            phase,
+            min: samples.first().copied().unwrap_or_default(),
+            median: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+            max: samples.last().copied().unwrap_or_default(),
+        };
+    }
+}
+
+/// Median timings of a previous benchmark, one `<DAY> <PHASE> <MEDIAN_NS>` per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: HashMap<(u8, Phase), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut medians = HashMap::new();
+        for line in non_empty_lines(input) {
+            let text = line.trimmed();
+            let (day, rest) = line.split_once(text, " ")?;
+            let (phase, median) = line.split_once(rest.trim_start(), " ")?;
+            let phase_value =
+                Phase::from_str(phase).ok_or(line.unexpected(phase, "parse, part1 or part2"))?;
+            medians.insert(
+                (line.parse_number::<u8>(day)?, phase_value),
+                Duration::from_nanos(line.parse_number::<u64>(median)?),
+            );
+        }
+        return Ok(Baseline { medians });
+    }
+
+    pub fn line(day: u8, timings: &Timings) -> String {
+        format!(
+            "{} {} {}",
+            day,
+            timings.phase.as_str(),
+            timings.median.as_nanos()
+        )
+    }
+
+    /// Relative change of the median compared to the baseline, in percent.
+    pub fn change(&self, day: u8, timings: &Timings) -> Option<f64> {
+        let baseline = self.medians.get(&(day, timings.phase))?;
+        if baseline.is_zero() {
+            return None;
+        }
+        let ratio = timings.median.as_nanos() as f64 / baseline.as_nanos() as f64;
+        return Some((ratio - 1.0) * 100.0);
+    }
+}
+
+pub fn describe(timings: &Timings, change: Option<f64>, threshold: f64) -> String {
+    let line = format!(
+        "{:<6} min {:>12} median {:>12} max {:>12}",
+        timings.phase.as_str(),
+        format!("{:?}", timings.min),
+        format!("{:?}", timings.median),
+        format!("{:?}", timings.max)
+    );
+    match change {
+        Some(change) if change > threshold => {
+            format!("{} ({:+.1}% vs baseline, regression)", line, change)
+        }
+        Some(change) => format!("{} ({:+.1}% vs baseline)", line, change),
+        None => line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_micros(*v)).collect()
+    }
+
+    #[test]
+    fn it_should_summarize_samples() {
+        let expected = Timings {
+            phase: Phase::Part1,
+            min: Duration::from_micros(2),
+            median: Duration::from_micros(5),
+            max: Duration::from_micros(40),
+        };
+        assert_eq!(
+            Timings::from_samples(Phase::Part1, micros(&[40, 5, 2, 7, 3])),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_compare_with_baseline() {
+        let timings = Timings::from_samples(Phase::Part2, micros(&[150]));
+        let baseline = Baseline::parse(&Baseline::line(12, &timings)).unwrap();
+        assert_eq!(baseline.change(12, &timings), Some(0.0));
+
+        let baseline = Baseline::parse("12 part2 100000\n12 parse 1000\n").unwrap();
+        assert_eq!(baseline.change(12, &timings), Some(50.0));
+        assert_eq!(baseline.change(13, &timings), None);
+        assert!(describe(&timings, Some(50.0), 20.0).ends_with("(+50.0% vs baseline, regression)"));
+        assert!(describe(&timings, Some(-5.0), 20.0).ends_with("(-5.0% vs baseline)"));
+    }
+
+    #[test]
+    fn it_should_report_malformed_baseline() {
+        assert!(Baseline::parse("12 part3 100\n").is_err());
+        assert!(Baseline::parse("12 part1 fast\n").is_err());
+        assert!(Baseline::parse("12 part1\n").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::bench::{Phase, Timings};
+use aoc_common::{Answer, Solution};
+use std::hint::black_box;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+pub const DAYS: [u8; 22] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21, 22, 23,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    NoAnswer,
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NoAnswer => "no-answer",
+            Status::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    pub status: Status,
+    /// The answer, or the error message when the part failed.
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+fn solve_part<T: Answer, F: FnOnce() -> Result<T, String>>(part: u8, solve: F) -> PartResult {
+    let start = Instant::now();
+    let result = solve();
+    let elapsed = start.elapsed();
+    let (status, answer) = match result.map(|output| output.answer()) {
+        Ok(Some(answer)) => (Status::Ok, answer),
+        Ok(None) => (Status::NoAnswer, String::new()),
+        Err(message) => (Status::Error, message),
+    };
+    return PartResult {
+        part,
+        status,
+        answer,
+        elapsed,
+    };
+}
+
+/// A solved day, whatever the types of its input and answers.
+pub trait Day {
+    fn run(&self, parts: &[u8], input: String) -> Vec<PartResult>;
+    fn bench(&self, input: &str, runs: usize) -> Result<Vec<Timings>, String>;
+}
+
+struct Runner<S>(PhantomData<S>);
+
+impl<S: Solution> Day for Runner<S> {
+    fn run(&self, parts: &[u8], input: String) -> Vec<PartResult> {
+        let start = Instant::now();
+        let data = match S::parse_data(input) {
+            Ok(data) => data,
+            Err(error) => {
+                let elapsed = start.elapsed();
+                return parts
+                    .iter()
+                    .map(|part| PartResult {
+                        part: *part,
+                        status: Status::Error,
+                        answer: error.to_string(),
+                        elapsed,
+                    })
+                    .collect();
+            }
+        };
+        return parts
+            .iter()
+            .map(|part| match part {
+                1 => solve_part(1, || S::part1(&data)),
+                _ => solve_part(2, || S::part2(&data)),
+            })
+            .collect();
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> Result<Vec<Timings>, String> {
+        let mut parse_samples = Vec::with_capacity(runs);
+        let mut part1_samples = Vec::with_capacity(runs);
+        let mut part2_samples = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let input = String::from(input);
+            let start = Instant::now();
+            let data = black_box(S::parse_data(input)).map_err(|e| e.to_string())?;
+            parse_samples.push(start.elapsed());
+
+            let start = Instant::now();
+            let _ = black_box(S::part1(&data));
+            part1_samples.push(start.elapsed());
+
+            let start = Instant::now();
+            let _ = black_box(S::part2(&data));
+            part2_samples.push(start.elapsed());
+        }
+        return Ok(vec![
+            Timings::from_samples(Phase::Parse, parse_samples),
+            Timings::from_samples(Phase::Part1, part1_samples),
+            Timings::from_samples(Phase::Part2, part2_samples),
+        ]);
+    }
+}
+
+fn runner<S: Solution + 'static>() -> Box<dyn Day> {
+    return Box::new(Runner::<S>(PhantomData));
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Day>> {
+    match day {
+        1 => Some(runner::<day01::Day01>()),
+        2 => Some(runner::<day02::Day02>()),
+        3 => Some(runner::<day03::Day03>()),
+        4 => Some(runner::<day04::Day04>()),
+        5 => Some(runner::<day05::Day05>()),
+        6 => Some(runner::<day06::Day06>()),
+        7 => Some(runner::<day07::Day07>()),
+        8 => Some(runner::<day08::Day08>()),
+        9 => Some(runner::<day09::Day09>()),
+        10 => Some(runner::<day10::Day10>()),
+        11 => Some(runner::<day11::Day11>()),
+        12 => Some(runner::<day12::Day12>()),
+        13 => Some(runner::<day13::Day13>()),
+        14 => Some(runner::<day14::Day14>()),
+        15 => Some(runner::<day15::Day15>()),
+        16 => Some(runner::<day16::Day16>()),
+        17 => Some(runner::<day17::Day17>()),
+        18 => Some(runner::<day18::Day18>()),
+        20 => Some(runner::<day20::Day20>()),
+        21 => Some(runner::<day21::Day21>()),
+        22 => Some(runner::<day22::Day22>()),
+        23 => Some(runner::<day23::Day23>()),
+        _ => None,
+    }
+}
+
+pub fn run(day: u8, parts: &[u8], input: String) -> Result<Vec<PartResult>, String> {
+    let solution = find(day).ok_or(format!("Day {} has no solution", day))?;
+    return Ok(solution.run(parts, input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(results: Vec<PartResult>) -> Vec<(u8, Status, String)> {
+        results
+            .into_iter()
+            .map(|result| (result.part, result.status, result.answer))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_run_both_parts() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let expected = vec![
+            (1, Status::Ok, String::from("7")),
+            (2, Status::Ok, String::from("5")),
+        ];
+        assert_eq!(
+            summary(run(1, &[1, 2], input.to_string()).unwrap()),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_run_only_requested_part() {
+        let input = "3,4,3,1,2\n";
+        let expected = vec![(2, Status::Ok, String::from("26984457539"))];
+        assert_eq!(summary(run(6, &[2], input.to_string()).unwrap()), expected);
+    }
+
+    #[test]
+    fn it_should_report_parse_errors() {
+        let input = "forward 5\ndown five\n";
+        let expected = vec![(
+            1,
+            Status::Error,
+            String::from("line 2, column 6: \"five\" is not a valid number"),
+        )];
+        assert_eq!(summary(run(2, &[1], input.to_string()).unwrap()), expected);
+    }
+
+    #[test]
+    fn it_should_reject_unknown_day() {
+        assert!(run(19, &[1], String::new()).is_err());
+    }
+}
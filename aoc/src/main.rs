@@ -0,0 +1,350 @@
+use answers::{Answers, Verdict};
+use aoc_common::{parse_part, read_input};
+use bench::Baseline;
+use days::Status;
+use report::Format;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+mod answers;
+mod bench;
+mod days;
+mod report;
+mod scaffold;
+
+const USAGE: &str =
+    "Usage: aoc run [--day <DAY>] [--part <1|2>] [--input <PATH>] [--format <text|json|csv>]
+               [--answers <PATH>]
+
+Without --day every solved day is run, without --part both parts are run.
+The default input is dayNN/input1.txt at the workspace root, use - to read it from stdin.
+With --answers each answer is checked against the expected ones, one `<DAY> <PART> <INPUT_HASH> <ANSWER>`
+per line, and the run fails when one of them differs.
+
+Usage: aoc bench [--day <DAY>] [--input <PATH>] [--runs <N>] [--baseline <PATH>]
+                 [--threshold <PERCENT>] [--save <PATH>]
+
+Runs the parsing and both parts --runs times (10 by default) and prints min/median/max timings.
+With --baseline medians are compared to a previous --save, and the benchmark fails when one of
+them is more than --threshold percent slower (20 by default).
+
+Usage: aoc new --day <DAY>
+
+Creates dayNN from the template, registers it in the workspace, the runner and the README table.";
+
+#[derive(Clone, Debug, PartialEq)]
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+    answers: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct BenchArgs {
+    day: Option<u8>,
+    input: Option<PathBuf>,
+    runs: usize,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    save: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_run_args(rest).and_then(run),
+        Some((command, rest)) if command == "bench" => parse_bench_args(rest).and_then(bench),
+        Some((command, rest)) if command == "new" => parse_new_args(rest).and_then(new_day),
+        _ => Err(String::from(USAGE)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs {
+        day: None,
+        part: None,
+        input: None,
+        format: Format::Text,
+        answers: None,
+    };
+    let mut args_it = args.iter();
+    while let Some(arg) = args_it.next() {
+        let value = args_it
+            .next()
+            .ok_or(format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        match arg.as_str() {
+            "--day" => run_args.day = Some(parse_number(arg, value)?),
+            "--part" => run_args.part = Some(parse_part(value)?),
+            "--input" => run_args.input = Some(PathBuf::from(value)),
+            "--format" => run_args.format = report::parse_format(value)?,
+            "--answers" => run_args.answers = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err(String::from("--input requires --day"));
+    }
+    return Ok(run_args);
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        input: None,
+        runs: 10,
+        baseline: None,
+        threshold: 20.0,
+        save: None,
+    };
+    let mut args_it = args.iter();
+    while let Some(arg) = args_it.next() {
+        let value = args_it
+            .next()
+            .ok_or(format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        match arg.as_str() {
+            "--day" => bench_args.day = Some(parse_number(arg, value)?),
+            "--input" => bench_args.input = Some(PathBuf::from(value)),
+            "--runs" => bench_args.runs = parse_number(arg, value)?,
+            "--baseline" => bench_args.baseline = Some(PathBuf::from(value)),
+            "--threshold" => bench_args.threshold = parse_number(arg, value)?,
+            "--save" => bench_args.save = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+    if bench_args.input.is_some() && bench_args.day.is_none() {
+        return Err(String::from("--input requires --day"));
+    }
+    if bench_args.runs == 0 {
+        return Err(String::from("--runs should be at least 1"));
+    }
+    return Ok(bench_args);
+}
+
+fn parse_new_args(args: &[String]) -> Result<u8, String> {
+    match args {
+        [arg, value] if arg == "--day" => parse_number(arg, value),
+        _ => Err(String::from(USAGE)),
+    }
+}
+
+fn parse_number<T: FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse::<T>()
+        .map_err(|_| format!("{} expects a number, found {:?}", arg, value))
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS.to_vec(),
+    };
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let answers = match &args.answers {
+        Some(path) => Some(load_answers(path)?),
+        None => None,
+    };
+    let mut failed_count = 0;
+    let mut regression_count = 0;
+    if let Some(header) = report::header(args.format) {
+        println!("{}", header);
+    }
+    for day in days {
+        let input_path = args.input.clone().unwrap_or_else(|| default_input(day));
+        let input = read_input(&input_path)
+            .map_err(|e| format!("An error occurred when reading {:?}: {}", input_path, e))?;
+        let input_hash = report::input_hash(&input);
+        if let Some(day_header) = report::day_header(args.format, day) {
+            println!("{}", day_header);
+        }
+        for result in days::run(day, &parts, input)? {
+            if result.status == Status::Error {
+                failed_count += 1;
+            }
+            let verdict = answers
+                .as_ref()
+                .map(|answers| answers.verdict(day, &input_hash, &result));
+            if let Some(Verdict::Fail { .. }) = verdict {
+                regression_count += 1;
+            }
+            println!(
+                "{}",
+                report::record(args.format, day, &input_hash, &result, verdict.as_ref())
+            );
+        }
+        if let Some(day_footer) = report::day_footer(args.format) {
+            println!("{}", day_footer);
+        }
+    }
+    if regression_count > 0 {
+        return Err(format!(
+            "{} part(s) differ from the expected answers",
+            regression_count
+        ));
+    }
+    if failed_count > 0 {
+        return Err(format!("{} part(s) failed", failed_count));
+    }
+    return Ok(());
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS.to_vec(),
+    };
+    let baseline = match &args.baseline {
+        Some(path) => Some(load_baseline(path)?),
+        None => None,
+    };
+    let mut saved_lines = Vec::new();
+    let mut regression_count = 0;
+    for day in days {
+        let solution = days::find(day).ok_or(format!("Day {} has no solution", day))?;
+        let input_path = args.input.clone().unwrap_or_else(|| default_input(day));
+        let input = read_input(&input_path)
+            .map_err(|e| format!("An error occurred when reading {:?}: {}", input_path, e))?;
+        println!("Day {:02}", day);
+        let timings = solution
+            .bench(&input, args.runs)
+            .map_err(|e| format!("An error occurred when parsing {:?}: {}", input_path, e))?;
+        for phase_timings in timings {
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.change(day, &phase_timings));
+            if change.is_some_and(|change| change > args.threshold) {
+                regression_count += 1;
+            }
+            println!(
+                "{}",
+                bench::describe(&phase_timings, change, args.threshold)
+            );
+            saved_lines.push(Baseline::line(day, &phase_timings));
+        }
+        println!("--------------------------------------------------");
+    }
+    if let Some(path) = &args.save {
+        std::fs::write(path, saved_lines.join("\n") + "\n")
+            .map_err(|e| format!("An error occurred when writing {:?}: {}", path, e))?;
+    }
+    if regression_count > 0 {
+        return Err(format!(
+            "{} phase(s) are slower than the baseline",
+            regression_count
+        ));
+    }
+    return Ok(());
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = read_input(path)
+        .map_err(|e| format!("An error occurred when reading {:?}: {}", path, e))?;
+    return Baseline::parse(&content)
+        .map_err(|e| format!("An error occurred when parsing {:?}: {}", path, e));
+}
+
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    let content = read_input(path)
+        .map_err(|e| format!("An error occurred when reading {:?}: {}", path, e))?;
+    return Answers::parse(&content)
+        .map_err(|e| format!("An error occurred when parsing {:?}: {}", path, e));
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let day_dir = scaffold::new_day(&workspace_root(), day)?;
+    println!("Created {:?}", day_dir);
+    return Ok(());
+}
+
+fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    return manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf();
+}
+
+fn default_input(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("day{:02}", day))
+        .join("input1.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn it_should_parse_run_args_correctly() {
+        let expected = RunArgs {
+            day: Some(15),
+            part: Some(2),
+            input: Some(PathBuf::from("path/to/input.txt")),
+            format: Format::Csv,
+            answers: Some(PathBuf::from("answers.txt")),
+        };
+        assert_eq!(
+            parse_run_args(&args(
+                "--day 15 --part 2 --input path/to/input.txt --format csv --answers answers.txt"
+            ))
+            .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_parse_empty_run_args_correctly() {
+        let expected = RunArgs {
+            day: None,
+            part: None,
+            input: None,
+            format: Format::Text,
+            answers: None,
+        };
+        assert_eq!(parse_run_args(&args("")).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_run_args() {
+        assert!(parse_run_args(&args("--part 3")).is_err());
+        assert!(parse_run_args(&args("--day")).is_err());
+        assert!(parse_run_args(&args("--day x")).is_err());
+        assert!(parse_run_args(&args("--input foo.txt")).is_err());
+        assert!(parse_run_args(&args("--verbose 1")).is_err());
+        assert!(parse_run_args(&args("--format xml")).is_err());
+    }
+
+    #[test]
+    fn it_should_parse_bench_args_correctly() {
+        let expected = BenchArgs {
+            day: Some(12),
+            input: None,
+            runs: 5,
+            baseline: Some(PathBuf::from("bench.txt")),
+            threshold: 10.0,
+            save: None,
+        };
+        assert_eq!(
+            parse_bench_args(&args(
+                "--day 12 --runs 5 --baseline bench.txt --threshold 10"
+            ))
+            .unwrap(),
+            expected
+        );
+        assert!(parse_bench_args(&args("--runs 0")).is_err());
+        assert!(parse_bench_args(&args("--input foo.txt")).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::answers::Verdict;
+use crate::days::{PartResult, Status};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(format!(
+            "Format should be text, json or csv, found {:?}",
+            value
+        )),
+    }
+}
+
+/// Hexadecimal FNV-1a hash of the input, to tell apart runs made on different datasets.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    return format!("{:016x}", hash);
+}
+
+pub fn header(format: Format) -> Option<&'static str> {
+    match format {
+        Format::Csv => Some("day,part,status,answer,elapsed_us,input_hash,verdict"),
+        _ => None,
+    }
+}
+
+pub fn day_header(format: Format, day: u8) -> Option<String> {
+    match format {
+        Format::Text => Some(format!("Day {:02}", day)),
+        _ => None,
+    }
+}
+
+pub fn day_footer(format: Format) -> Option<&'static str> {
+    match format {
+        Format::Text => Some("--------------------------------------------------"),
+        _ => None,
+    }
+}
+
+/// One line describing `result`, the verdict being only known when an answers file is used.
+pub fn record(
+    format: Format,
+    day: u8,
+    input_hash: &str,
+    result: &PartResult,
+    verdict: Option<&Verdict>,
+) -> String {
+    match format {
+        Format::Text => {
+            let line = match result.status {
+                // Drawings such as day 13's folded page start on their own line.
+                Status::Ok if result.answer.contains('\n') => {
+                    format!("Part {}:\n{}", result.part, result.answer)
+                }
+                Status::Ok => format!("Part {}: {}", result.part, result.answer),
+                Status::NoAnswer => format!("Part {}: no answer", result.part),
+                Status::Error => format!("Part {}: error: {}", result.part, result.answer),
+            };
+            match verdict {
+                None => line,
+                Some(Verdict::Fail { expected }) => {
+                    format!("{} (fail, expected {})", line, expected)
+                }
+                Some(verdict) => format!("{} ({})", line, verdict.as_str()),
+            }
+        }
+        Format::Json => format!(
+            "{{\"day\":{},\"part\":{},\"status\":{},\"answer\":{},\"elapsed_us\":{},\"input_hash\":{},\"verdict\":{}}}",
+            day,
+            result.part,
+            json_string(result.status.as_str()),
+            json_string(&result.answer),
+            result.elapsed.as_micros(),
+            json_string(input_hash),
+            verdict.map_or(String::from("null"), |verdict| json_string(verdict.as_str()))
+        ),
+        Format::Csv => format!(
+            "{},{},{},{},{},{},{}",
+            day,
+            result.part,
+            result.status.as_str(),
+            csv_field(&result.answer),
+            result.elapsed.as_micros(),
+            input_hash,
+            verdict.map_or("", |verdict| verdict.as_str())
+        ),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    return escaped;
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", s.replace('"', "\"\""));
+    }
+    return String::from(s);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample_result() -> PartResult {
+        PartResult {
+            part: 2,
+            status: Status::Error,
+            answer: String::from("line 2, column 6: \"five\" is not a valid number"),
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn it_should_hash_input() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn it_should_format_json_record() {
+        let expected = "{\"day\":2,\"part\":2,\"status\":\"error\",\"answer\":\"line 2, column 6: \\\"five\\\" is not a valid number\",\"elapsed_us\":1500,\"input_hash\":\"cbf29ce484222325\",\"verdict\":null}";
+        assert_eq!(
+            record(Format::Json, 2, "cbf29ce484222325", &sample_result(), None),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_format_csv_record() {
+        let expected =
+            "2,2,error,\"line 2, column 6: \"\"five\"\" is not a valid number\",1500,cbf29ce484222325,fail";
+        let verdict = Verdict::Fail {
+            expected: String::from("42"),
+        };
+        assert_eq!(
+            record(
+                Format::Csv,
+                2,
+                "cbf29ce484222325",
+                &sample_result(),
+                Some(&verdict)
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_format_text_record() {
+        let result = PartResult {
+            part: 1,
+            status: Status::Ok,
+            answer: String::from("7"),
+            elapsed: Duration::from_micros(3),
+        };
+        assert_eq!(record(Format::Text, 1, "", &result, None), "Part 1: 7");
+        let drawing = PartResult {
+            answer: String::from("█░\n░█"),
+            ..result.clone()
+        };
+        assert_eq!(
+            record(Format::Text, 1, "", &drawing, None),
+            "Part 1:\n█░\n░█"
+        );
+        assert_eq!(
+            record(Format::Text, 1, "", &result, Some(&Verdict::Pass)),
+            "Part 1: 7 (pass)"
+        );
+        let verdict = Verdict::Fail {
+            expected: String::from("8"),
+        };
+        assert_eq!(
+            record(Format::Text, 1, "", &result, Some(&verdict)),
+            "Part 1: 7 (fail, expected 8)"
+        );
+    }
+}
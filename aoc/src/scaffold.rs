@@ -0,0 +1,298 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_FILES: [&str; 5] = [
+    "Cargo.toml",
+    "input1.txt",
+    "input_test.txt",
+    "src/lib.rs",
+    "src/main.rs",
+];
+
+/// Creates `dayNN` from `template` and registers it in the workspace, the runner and the README.
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day should be between 1 and 25, found {}", day));
+    }
+    let name = format!("day{:02}", day);
+    let day_dir = root.join(&name);
+    if day_dir.exists() {
+        return Err(format!("{:?} already exists", day_dir));
+    }
+
+    let mut writes: Vec<(PathBuf, String)> = Vec::new();
+    for file in TEMPLATE_FILES {
+        let content = read(&root.join("template").join(file))?;
+        writes.push((
+            day_dir.join(file),
+            render_template_file(file, &content, day),
+        ));
+    }
+    let workspace_manifest = root.join("Cargo.toml");
+    let content = add_workspace_member(&read(&workspace_manifest)?, &name)?;
+    writes.push((workspace_manifest, content));
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let content = add_dependency(&read(&runner_manifest)?, &name)?;
+    writes.push((runner_manifest, content));
+    let runner_days = root.join("aoc").join("src").join("days.rs");
+    let content = register_day(&read(&runner_days)?, day)?;
+    writes.push((runner_days.clone(), content));
+    let readme = root.join("README.md");
+    let content = update_readme(&read(&readme)?, day)?;
+    writes.push((readme, content));
+
+    fs::create_dir_all(day_dir.join("src"))
+        .map_err(|e| format!("An error occurred when creating {:?}: {}", day_dir, e))?;
+    for (path, content) in writes {
+        fs::write(&path, content)
+            .map_err(|e| format!("An error occurred when writing {:?}: {}", path, e))?;
+    }
+    // Keep the dispatch table formatted, rustfmt being optional.
+    let _ = std::process::Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(&runner_days)
+        .status();
+    return Ok(day_dir);
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("An error occurred when reading {:?}: {}", path, e))
+}
+
+fn render_template_file(file: &str, content: &str, day: u8) -> String {
+    let name = format!("day{:02}", day);
+    let type_name = format!("Day{:02}", day);
+    match file {
+        "Cargo.toml" => {
+            let manifest = content.replacen("name = \"day\"", &format!("name = \"{}\"", name), 1);
+            return format!("{}\n\n[lints]\nworkspace = true\n", manifest.trim_end());
+        }
+        "src/main.rs" => {
+            let main = content.replace("use day::", &format!("use {}::", name));
+            return rename_identifier(&main, "Day", &type_name);
+        }
+        "src/lib.rs" => rename_identifier(content, "Day", &type_name),
+        _ => String::from(content),
+    }
+}
+
+fn rename_identifier(text: &str, from: &str, to: &str) -> String {
+    let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut renamed = String::new();
+    let mut rest = text;
+    while let Some(position) = rest.find(from) {
+        let before = rest[..position].chars().last();
+        let after = rest[position + from.len()..].chars().next();
+        renamed.push_str(&rest[..position]);
+        if before.is_none_or(|c| !is_identifier_char(c))
+            && after.is_none_or(|c| !is_identifier_char(c))
+        {
+            renamed.push_str(to);
+        } else {
+            renamed.push_str(from);
+        }
+        rest = &rest[position + from.len()..];
+    }
+    renamed.push_str(rest);
+    return renamed;
+}
+
+/// Inserts `line` before the first line of `lines[start..end]` matching `is_after`, or at `end`.
+fn insert_sorted<F: Fn(&str) -> bool>(
+    lines: &mut Vec<String>,
+    start: usize,
+    end: usize,
+    line: String,
+    is_after: F,
+) {
+    let position = (start..end).find(|i| is_after(&lines[*i])).unwrap_or(end);
+    lines.insert(position, line);
+}
+
+fn add_workspace_member(manifest: &str, name: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or("No workspace members in Cargo.toml")?
+        + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("Unterminated workspace members in Cargo.toml")?;
+    let member = format!("\"{}\",", name);
+    if lines[start..end].iter().any(|line| line.trim() == member) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+    insert_sorted(&mut lines, start, end, format!("    {}", member), |line| {
+        line.trim().starts_with("\"day") && line.trim() > member.as_str()
+    });
+    return Ok(lines.join("\n") + "\n");
+}
+
+fn add_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .ok_or("No dependencies in aoc/Cargo.toml")?
+        + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim().is_empty() || line.starts_with('['))
+            .unwrap_or(lines.len() - start);
+    let key = format!("{} =", name);
+    if lines[start..end].iter().any(|line| line.starts_with(&key)) {
+        return Err(format!("{} is already a dependency of aoc", name));
+    }
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    insert_sorted(&mut lines, start, end, dependency, |line| {
+        line.starts_with("day") && line > key.as_str()
+    });
+    return Ok(lines.join("\n") + "\n");
+}
+
+fn register_day(days: &str, day: u8) -> Result<String, String> {
+    let declaration_start = days
+        .find("pub const DAYS")
+        .ok_or("No DAYS list in aoc/src/days.rs")?;
+    let list_start = declaration_start
+        + days[declaration_start..]
+            .find("= [")
+            .ok_or("Malformed DAYS list in aoc/src/days.rs")?
+        + 3;
+    let list_end = list_start
+        + days[list_start..]
+            .find("];")
+            .ok_or("Malformed DAYS list in aoc/src/days.rs")?;
+    let mut solved: Vec<u8> = days[list_start..list_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<u8>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| "Malformed DAYS list in aoc/src/days.rs")?;
+    if solved.contains(&day) {
+        return Err(format!("Day {} is already registered in the runner", day));
+    }
+    solved.push(day);
+    solved.sort();
+
+    let mut list_lines = vec![String::new()];
+    for value in &solved {
+        let item = format!("{},", value);
+        let last = list_lines.last_mut().unwrap();
+        if !last.is_empty() && 4 + last.len() + 1 + item.len() > 100 {
+            list_lines.push(item);
+        } else if last.is_empty() {
+            last.push_str(&item);
+        } else {
+            last.push(' ');
+            last.push_str(&item);
+        }
+    }
+    let list = list_lines
+        .iter()
+        .map(|line| format!("\n    {}", line))
+        .collect::<String>();
+    let registered = format!(
+        "{}pub const DAYS: [u8; {}] = [{}\n{}",
+        &days[..declaration_start],
+        solved.len(),
+        list,
+        &days[list_end..]
+    );
+
+    let mut lines: Vec<String> = registered.lines().map(String::from).collect();
+    let fallback = lines
+        .iter()
+        .position(|line| line.trim() == "_ => None,")
+        .ok_or("No fallback arm in aoc/src/days.rs")?;
+    let position = lines[..fallback]
+        .iter()
+        .position(|line| match line.trim().split_once(" => Some(runner::<") {
+            Some((number, _)) => number.parse::<u8>().is_ok_and(|other| other > day),
+            None => false,
+        })
+        .unwrap_or(fallback);
+    let arm = format!(
+        "        {} => Some(runner::<day{:02}::Day{:02}>()),",
+        day, day, day
+    );
+    lines.insert(position, arm);
+    return Ok(lines.join("\n") + "\n");
+}
+
+fn update_readme(readme: &str, day: u8) -> Result<String, String> {
+    let mut found = false;
+    let lines: Vec<String> = readme
+        .lines()
+        .map(|line| match line.split_once('|') {
+            Some((number, _)) if number.trim() == day.to_string() => {
+                found = true;
+                format!("{:<4}| ⚙️       | ⚙️      ", day)
+            }
+            _ => String::from(line),
+        })
+        .collect();
+    if !found {
+        return Err(format!("No row for day {} in the README table", day));
+    }
+    return Ok(lines.join("\n") + "\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_template_files() {
+        let main =
+            "use aoc_common::run_cli;\nuse day::Day;\n\nfn main() {\n    run_cli::<Day>()\n}\n";
+        let expected =
+            "use aoc_common::run_cli;\nuse day24::Day24;\n\nfn main() {\n    run_cli::<Day24>()\n}\n";
+        assert_eq!(render_template_file("src/main.rs", main, 24), expected);
+        let lib = "pub struct Day;\nimpl Solution for Day {}\nlet x = Day::parse_data(DayOne);";
+        let expected =
+            "pub struct Day24;\nimpl Solution for Day24 {}\nlet x = Day24::parse_data(DayOne);";
+        assert_eq!(render_template_file("src/lib.rs", lib, 24), expected);
+        let manifest = "[package]\nname = \"day\"\n";
+        let expected = "[package]\nname = \"day24\"\n\n[lints]\nworkspace = true\n";
+        assert_eq!(render_template_file("Cargo.toml", manifest, 24), expected);
+    }
+
+    #[test]
+    fn it_should_register_workspace_member_and_dependency() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day18\",\n    \"day20\",\n]\n";
+        let expected =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day18\",\n    \"day19\",\n    \"day20\",\n]\n";
+        assert_eq!(add_workspace_member(manifest, "day19").unwrap(), expected);
+        assert!(add_workspace_member(manifest, "day18").is_err());
+
+        let manifest = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday18 = { path = \"../day18\" }\n\n[lints]\n";
+        let expected = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday18 = { path = \"../day18\" }\nday24 = { path = \"../day24\" }\n\n[lints]\n";
+        assert_eq!(add_dependency(manifest, "day24").unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_register_day_in_runner() {
+        let days = "pub const DAYS: [u8; 2] = [\n    1, 20,\n];\n\nmatch day {\n        1 => Some(runner::<day01::Day01>()),\n        20 => Some(runner::<day20::Day20>()),\n        _ => None,\n}\n";
+        let expected = "pub const DAYS: [u8; 3] = [\n    1, 19, 20,\n];\n\nmatch day {\n        1 => Some(runner::<day01::Day01>()),\n        19 => Some(runner::<day19::Day19>()),\n        20 => Some(runner::<day20::Day20>()),\n        _ => None,\n}\n";
+        assert_eq!(register_day(days, 19).unwrap(), expected);
+        assert!(register_day(days, 20).is_err());
+    }
+
+    #[test]
+    fn it_should_update_readme_table() {
+        let readme =
+            "Day | Part 1   | Part 2\n--- | -------- | --------\n19  | 🕕       | 🕕      \n";
+        let expected =
+            "Day | Part 1   | Part 2\n--- | -------- | --------\n19  | ⚙️       | ⚙️      \n";
+        assert_eq!(update_readme(readme, 19).unwrap(), expected);
+        assert!(update_readme(readme, 26).is_err());
+    }
+}
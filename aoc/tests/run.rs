@@ -0,0 +1,75 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const DAY13_SAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+fn run_aoc(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+#[test]
+fn it_should_only_print_records_in_json_mode() {
+    let stdout = run_aoc(
+        &["run", "--day", "13", "--input", "-", "--format", "json"],
+        DAY13_SAMPLE,
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    for (line, part) in lines.iter().zip(["1", "2"]) {
+        assert!(line.starts_with(&format!("{{\"day\":13,\"part\":{},", part)));
+        assert!(line.ends_with('}'));
+    }
+    assert!(lines[1].contains("\"answer\":\"█████\\n█░░░█\\n"));
+}
+
+#[test]
+fn it_should_only_print_records_in_csv_mode() {
+    let stdout = run_aoc(
+        &["run", "--day", "13", "--input", "-", "--format", "csv"],
+        DAY13_SAMPLE,
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[0],
+        "day,part,status,answer,elapsed_us,input_hash,verdict"
+    );
+    assert!(lines[1].starts_with("13,1,ok,17,"));
+    // The folded page is quoted, its rows spanning the following lines.
+    assert!(lines[2].starts_with("13,2,ok,\"█████"));
+    assert_eq!(lines.len(), 2 + 7);
+}
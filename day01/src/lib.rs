@@ -0,0 +1,69 @@
+use aoc_common::{non_empty_lines, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input)
+            .map(|line| line.parse_number::<u32>(line.trimmed()))
+            .collect();
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(data
+            .iter()
+            .tuple_windows::<(&u32, &u32)>()
+            .filter(|(x, y)| x < y)
+            .count());
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        return Self::part1(
+            &data
+                .iter()
+                .tuple_windows::<(&u32, &u32, &u32)>()
+                .map(|(x, y, z)| x + y + z)
+                .collect::<Vec<u32>>(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+        let expected = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(Day01::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let expected = 7;
+        assert_eq!(Day01::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let expected = 5;
+        assert_eq!(Day01::part2(&data).unwrap(), expected);
+    }
+}
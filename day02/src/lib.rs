@@ -0,0 +1,166 @@
+use aoc_common::{non_empty_lines, ParseError, Solution};
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Position {
+    horizontal_position: u32,
+    depth: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Position2 {
+    horizontal_position: u32,
+    depth: u32,
+    aim: u32,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input)
+            .map(|line| {
+                let (command, x) = line.split_once(line.trimmed(), " ")?;
+                let n: u32 = line.parse_number(x)?;
+                match command {
+                    "forward" => Ok(Command::Forward(n)),
+                    "down" => Ok(Command::Down(n)),
+                    "up" => Ok(Command::Up(n)),
+                    _ => Err(line.unexpected(command, "forward, down or up")),
+                }
+            })
+            .collect();
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        let position = data.iter().fold(
+            Position {
+                horizontal_position: 0,
+                depth: 0,
+            },
+            |position, command| match command {
+                Command::Forward(x) => Position {
+                    horizontal_position: position.horizontal_position + x,
+                    depth: position.depth,
+                },
+                Command::Down(x) => Position {
+                    horizontal_position: position.horizontal_position,
+                    depth: position.depth + x,
+                },
+                Command::Up(x) => Position {
+                    horizontal_position: position.horizontal_position,
+                    depth: position.depth - x,
+                },
+            },
+        );
+        return Ok(position.horizontal_position * position.depth);
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        let position = data.iter().fold(
+            Position2 {
+                horizontal_position: 0,
+                depth: 0,
+                aim: 0,
+            },
+            |position, command| match command {
+                Command::Forward(x) => Position2 {
+                    horizontal_position: position.horizontal_position + x,
+                    depth: position.depth + (x * position.aim),
+                    aim: position.aim,
+                },
+                Command::Down(x) => Position2 {
+                    horizontal_position: position.horizontal_position,
+                    depth: position.depth,
+                    aim: position.aim + x,
+                },
+                Command::Up(x) => Position2 {
+                    horizontal_position: position.horizontal_position,
+                    depth: position.depth,
+                    aim: position.aim - x,
+                },
+            },
+        );
+        return Ok(position.horizontal_position * position.depth);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "forward 5
+        down 5
+        forward 8
+        up 3
+        down 8
+        forward 2";
+        let expected: Vec<Command> = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        assert_eq!(Day02::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_report_malformed_input() {
+        let input = "forward 5\ndown five\n";
+        let expected = ParseError::InvalidNumber {
+            line: 2,
+            column: 6,
+            text: String::from("five"),
+        };
+        assert_eq!(Day02::parse_data(input.to_string()), Err(expected));
+        let input = "forward 5\nbackward 2\n";
+        let expected = ParseError::UnexpectedToken {
+            line: 2,
+            column: 1,
+            text: String::from("backward"),
+            expected: String::from("forward, down or up"),
+        };
+        assert_eq!(Day02::parse_data(input.to_string()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        let expected = 150;
+        assert_eq!(Day02::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        let expected = 900;
+        assert_eq!(Day02::part2(&data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,247 @@
+use aoc_common::{convert_to_column_matrix, missing_at_end, non_empty_lines, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Bit {
+    High,
+    Low,
+}
+
+fn char_to_bit(c: char) -> Option<Bit> {
+    match c {
+        '0' => Some(Bit::Low),
+        '1' => Some(Bit::High),
+        _ => None,
+    }
+}
+fn bit_to_char(bit: &Bit) -> char {
+    match bit {
+        Bit::High => '1',
+        Bit::Low => '0',
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    number_width: usize,
+    numbers: Vec<String>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Diagnostic;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut lines: Vec<String> = Vec::new();
+        let mut number_width: Option<usize> = None;
+        for line in non_empty_lines(&input) {
+            let number = line.trimmed();
+            line.check_chars(number, |c| c == '0' || c == '1', "a binary digit")?;
+            match number_width {
+                Some(expected) if expected != number.len() => {
+                    return Err(ParseError::WrongLength {
+                        line: line.number,
+                        expected,
+                        found: number.len(),
+                    });
+                }
+                _ => number_width = Some(number.len()),
+            }
+            lines.push(String::from(number));
+        }
+        let number_width = number_width.ok_or_else(|| missing_at_end(&input, "a number"))?;
+        return Ok(Diagnostic {
+            number_width,
+            numbers: lines,
+        });
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        let bit_matrix: Vec<Vec<Bit>> = diagnostic_to_bit_matrix(data);
+        let column_bit_matrix = convert_to_column_matrix(&bit_matrix, data.number_width);
+        let gamma: Vec<Bit> = column_bit_matrix
+            .iter()
+            .map(|column| {
+                let (high, low): (Vec<Bit>, Vec<Bit>) =
+                    column.iter().partition(|bit| **bit == Bit::High);
+                if high.len() >= low.len() {
+                    return Bit::High;
+                } else {
+                    return Bit::Low;
+                }
+            })
+            .collect();
+        let epsilon = permut_bits(&gamma);
+        let gamma = bits_to_u32(&gamma);
+        let epsilon = bits_to_u32(&epsilon);
+        return Ok(gamma * epsilon);
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        let oxygen = find_number(data, Bit::High, Bit::Low);
+        let co2 = find_number(data, Bit::Low, Bit::High);
+        return Ok(oxygen * co2);
+    }
+}
+
+fn diagnostic_to_bit_matrix(data: &Diagnostic) -> Vec<Vec<Bit>> {
+    data.numbers
+        .iter()
+        .map(|x| x.chars().filter_map(char_to_bit).collect())
+        .collect()
+}
+
+fn permut_bits(bits: &Vec<Bit>) -> Vec<Bit> {
+    bits.iter()
+        .map(|b| match b {
+            Bit::High => Bit::Low,
+            Bit::Low => Bit::High,
+        })
+        .collect()
+}
+
+fn bits_to_u32(bits: &Vec<Bit>) -> u32 {
+    let binary = bits
+        .iter()
+        .map(bit_to_char)
+        .map(String::from)
+        .collect::<Vec<String>>()
+        .join("");
+    return u32::from_str_radix(binary.as_str(), 2).expect("number should be convertible to u32");
+}
+
+fn grab_column(bit_matrix: &Vec<Vec<Bit>>, column_index: usize) -> &Vec<Bit> {
+    bit_matrix.get(column_index).expect("column should exist")
+}
+
+fn compute_which_bit_to_keep(
+    column: &Vec<Bit>,
+    high_most_present: Bit,
+    low_most_present: Bit,
+) -> Bit {
+    let (high, low): (Vec<Bit>, Vec<Bit>) = column.iter().partition(|bit| **bit == Bit::High);
+    if high.len() >= low.len() {
+        return high_most_present;
+    } else {
+        return low_most_present;
+    }
+}
+
+fn filter_matrix_with_keep_bit(
+    bit_matrix: &Vec<Vec<Bit>>,
+    column_index: usize,
+    keep: Bit,
+) -> Vec<Vec<Bit>> {
+    bit_matrix
+        .iter()
+        .filter(|n| *(n.get(column_index).unwrap()) == keep)
+        .cloned()
+        .collect()
+}
+
+fn find_number(data: &Diagnostic, high_most_present: Bit, low_most_present: Bit) -> u32 {
+    let mut bit_matrix: Vec<Vec<Bit>> = diagnostic_to_bit_matrix(data);
+    let mut index = 0;
+    while bit_matrix.len() > 1 && index < data.number_width {
+        let column_bit_matrix = convert_to_column_matrix(&bit_matrix, data.number_width);
+        let column = grab_column(&column_bit_matrix, index);
+        let keep = compute_which_bit_to_keep(column, high_most_present, low_most_present);
+        bit_matrix = filter_matrix_with_keep_bit(&bit_matrix, index, keep);
+        index += 1;
+    }
+    return bits_to_u32(&(bit_matrix[0]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "00100
+        11110
+        10110
+        10111
+        10101
+        01111
+        00111
+        11100
+        10000
+        11001
+        00010
+        01010";
+        let expected = Diagnostic {
+            number_width: 5,
+            numbers: vec![
+                String::from("00100"),
+                String::from("11110"),
+                String::from("10110"),
+                String::from("10111"),
+                String::from("10101"),
+                String::from("01111"),
+                String::from("00111"),
+                String::from("11100"),
+                String::from("10000"),
+                String::from("11001"),
+                String::from("00010"),
+                String::from("01010"),
+            ],
+        };
+        assert_eq!(Day03::parse_data(input.to_string()).unwrap(), expected);
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 3,
+            text: String::from("é"),
+            expected: String::from("a binary digit"),
+        };
+        assert_eq!(Day03::parse_data(String::from("10é1\n")), Err(expected));
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = Diagnostic {
+            number_width: 5,
+            numbers: vec![
+                String::from("00100"),
+                String::from("11110"),
+                String::from("10110"),
+                String::from("10111"),
+                String::from("10101"),
+                String::from("01111"),
+                String::from("00111"),
+                String::from("11100"),
+                String::from("10000"),
+                String::from("11001"),
+                String::from("00010"),
+                String::from("01010"),
+            ],
+        };
+        let expected = 198;
+        assert_eq!(Day03::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = Diagnostic {
+            number_width: 5,
+            numbers: vec![
+                String::from("00100"),
+                String::from("11110"),
+                String::from("10110"),
+                String::from("10111"),
+                String::from("10101"),
+                String::from("01111"),
+                String::from("00111"),
+                String::from("11100"),
+                String::from("10000"),
+                String::from("11001"),
+                String::from("00010"),
+                String::from("01010"),
+            ],
+        };
+        let expected = 230;
+        assert_eq!(Day03::part2(&data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,445 @@
+use aoc_common::{convert_to_column_matrix, input_lines, missing_at_end, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BoardNumberState {
+    Idle,
+    Marked,
+}
+
+type BoardRow = Vec<(u32, BoardNumberState)>;
+type Board = Vec<BoardRow>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Game {
+    random_numbers: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+pub struct Day04;
+
+/// A board needs as many rows as columns, `line` being the one of its last row.
+fn check_square(board: Board, line: usize) -> Result<Board, ParseError> {
+    let width = board[0].len();
+    if board.len() != width {
+        return Err(ParseError::WrongLength {
+            line,
+            expected: width,
+            found: board.len(),
+        });
+    }
+    return Ok(board);
+}
+
+impl Solution for Day04 {
+    type Input = Game;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut lines_it = input_lines(&input);
+        // random numbers
+        let random_numbers_line = lines_it
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| missing_at_end(&input, "random numbers"))?;
+        let random_numbers = random_numbers_line
+            .text
+            .split(",")
+            .filter(|x| !x.trim().is_empty())
+            .map(|x| random_numbers_line.parse_number::<u32>(x))
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+        // boards
+        let mut boards: Vec<Board> = Vec::new();
+        let mut board: Board = Vec::new();
+        let mut last_row_line = 0;
+        for line in lines_it {
+            if line.is_empty() {
+                if !board.is_empty() {
+                    boards.push(check_square(board, last_row_line)?);
+                }
+                board = Vec::new();
+            } else {
+                let row = line
+                    .text
+                    .split_whitespace()
+                    .map(|x| line.parse_number::<u32>(x))
+                    .map(|x| x.map(|x| (x, BoardNumberState::Idle)))
+                    .collect::<Result<BoardRow, ParseError>>()?;
+                if let Some(first_row) = board.first() {
+                    if row.len() != first_row.len() {
+                        return Err(ParseError::WrongLength {
+                            line: line.number,
+                            expected: first_row.len(),
+                            found: row.len(),
+                        });
+                    }
+                }
+                board.push(row);
+                last_row_line = line.number;
+            }
+        }
+        if !board.is_empty() {
+            boards.push(check_square(board, last_row_line)?);
+        }
+
+        return Ok(Game {
+            random_numbers,
+            boards,
+        });
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        let random_numbers_it = data.random_numbers.iter();
+        let mut boards_state = data.boards.clone();
+        for current_number in random_numbers_it {
+            boards_state = boards_state
+                .iter()
+                .map(|board| mark_number(board, *current_number))
+                .collect();
+            if boards_state.iter().any(is_winner_board) {
+                let winner = boards_state.iter().find(is_winner_board_find).unwrap();
+                let sum: u32 = winner
+                    .iter()
+                    .flat_map(|row| {
+                        row.iter()
+                            .filter(|(_, state)| *state == BoardNumberState::Idle)
+                            .copied()
+                            .collect::<BoardRow>()
+                    })
+                    .map(|(n, _)| n)
+                    .sum();
+                return Ok(current_number * sum);
+            }
+        }
+        return Err(String::from("No winner board found"));
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        let random_numbers_it = data.random_numbers.iter();
+        let mut boards_state = data.boards.clone();
+        for current_number in random_numbers_it {
+            boards_state = boards_state
+                .iter()
+                .map(|board| mark_number(board, *current_number))
+                .collect();
+            if boards_state.iter().any(is_winner_board) {
+                if boards_state.len() > 1 {
+                    boards_state = boards_state
+                        .iter()
+                        .filter(|board| !is_winner_board(board))
+                        .cloned()
+                        .collect();
+                } else {
+                    let winner = boards_state.iter().find(is_winner_board_find).unwrap();
+                    let sum: u32 = winner
+                        .iter()
+                        .flat_map(|row| {
+                            row.iter()
+                                .filter(|(_, state)| *state == BoardNumberState::Idle)
+                                .copied()
+                                .collect::<BoardRow>()
+                        })
+                        .map(|(n, _)| n)
+                        .sum();
+                    return Ok(current_number * sum);
+                }
+            }
+        }
+        return Err(String::from("No winner board found"));
+    }
+}
+
+fn is_winner_row(row: &BoardRow) -> bool {
+    row.iter()
+        .all(|(_, state)| *state == BoardNumberState::Marked)
+}
+fn is_winner_board(board: &Board) -> bool {
+    return board.iter().any(is_winner_row)
+        || convert_to_column_matrix(board, board[0].len())
+            .iter()
+            .any(is_winner_row);
+}
+fn is_winner_board_find(board: &&Board) -> bool {
+    is_winner_board(board)
+}
+
+fn mark_number(board: &Board, current_number: u32) -> Board {
+    board
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(n, state)| {
+                    if *n == current_number {
+                        (*n, BoardNumberState::Marked)
+                    } else {
+                        (*n, *state)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoardNumberState::*;
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+        22 13 17 11  0
+         8  2 23  4 24
+        21  9 14 16  7
+         6 10  3 18  5
+         1 12 20 15 19
+        
+         3 15  0  2 22
+         9 18 13 17  5
+        19  8  7 25 23
+        20 11 10 24  4
+        14 21 16 12  6
+        
+        14 21 17 24  4
+        10 16 15  9 19
+        18  8 23 26 20
+        22 11 13  6  5
+         2  0 12  3  7";
+        let expected = Game {
+            random_numbers: vec![
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1,
+            ],
+            boards: vec![
+                vec![
+                    vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+                    vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+                    vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+                    vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+                    vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+                ],
+                vec![
+                    vec![(3, Idle), (15, Idle), (0, Idle), (2, Idle), (22, Idle)],
+                    vec![(9, Idle), (18, Idle), (13, Idle), (17, Idle), (5, Idle)],
+                    vec![(19, Idle), (8, Idle), (7, Idle), (25, Idle), (23, Idle)],
+                    vec![(20, Idle), (11, Idle), (10, Idle), (24, Idle), (4, Idle)],
+                    vec![(14, Idle), (21, Idle), (16, Idle), (12, Idle), (6, Idle)],
+                ],
+                vec![
+                    vec![(14, Idle), (21, Idle), (17, Idle), (24, Idle), (4, Idle)],
+                    vec![(10, Idle), (16, Idle), (15, Idle), (9, Idle), (19, Idle)],
+                    vec![(18, Idle), (8, Idle), (23, Idle), (26, Idle), (20, Idle)],
+                    vec![(22, Idle), (11, Idle), (13, Idle), (6, Idle), (5, Idle)],
+                    vec![(2, Idle), (0, Idle), (12, Idle), (3, Idle), (7, Idle)],
+                ],
+            ],
+        };
+        assert_eq!(Day04::parse_data(input.to_string()).unwrap(), expected);
+        assert_eq!(
+            Day04::parse_data("1,2\n\n1 2\n3\n".to_string()),
+            Err(ParseError::WrongLength {
+                line: 4,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Day04::parse_data("1,2\n\n1 2\n3 4\n5 6\n\n1 2\n3 4\n".to_string()),
+            Err(ParseError::WrongLength {
+                line: 5,
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_compute_winning_board_correctly() {
+        let board1: Board = vec![
+            vec![(22, Marked), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Marked), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Marked), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Marked), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Marked), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        let board2: Board = vec![
+            vec![
+                (22, Marked),
+                (13, Marked),
+                (17, Marked),
+                (11, Marked),
+                (0, Marked),
+            ],
+            vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        assert!(is_winner_board(&board1));
+        assert!(is_winner_board(&board2));
+    }
+
+    #[test]
+    fn it_should_compute_loosing_board_correctly() {
+        let board1: Board = vec![
+            vec![(22, Marked), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Marked), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Marked), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Marked), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        let board2: Board = vec![
+            vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![
+                (8, Marked),
+                (2, Marked),
+                (23, Marked),
+                (4, Idle),
+                (24, Marked),
+            ],
+            vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        assert!(!is_winner_board(&board1));
+        assert!(!is_winner_board(&board2));
+    }
+
+    #[test]
+    fn it_should_mark_number_on_board_correctly_1() {
+        let board: Board = vec![
+            vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        let expected: Board = vec![
+            vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Idle), (9, Marked), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        assert_eq!(mark_number(&board, 9), expected);
+    }
+    #[test]
+    fn it_should_mark_number_on_board_correctly_2() {
+        let board: Board = vec![
+            vec![(22, Marked), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Marked), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Marked), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Marked), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        let expected: Board = vec![
+            vec![(22, Marked), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![(8, Marked), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+            vec![(21, Marked), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Marked), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Marked), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        assert_eq!(mark_number(&board, 6), expected);
+    }
+    #[test]
+    fn it_should_mark_number_on_board_correctly_3() {
+        let board: Board = vec![
+            vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![
+                (8, Marked),
+                (2, Marked),
+                (23, Marked),
+                (4, Idle),
+                (24, Marked),
+            ],
+            vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        let expected: Board = vec![
+            vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+            vec![
+                (8, Marked),
+                (2, Marked),
+                (23, Marked),
+                (4, Marked),
+                (24, Marked),
+            ],
+            vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+            vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+            vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+        ];
+        assert_eq!(mark_number(&board, 4), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = Game {
+            random_numbers: vec![
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1,
+            ],
+            boards: vec![
+                vec![
+                    vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+                    vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+                    vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+                    vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+                    vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+                ],
+                vec![
+                    vec![(3, Idle), (15, Idle), (0, Idle), (2, Idle), (22, Idle)],
+                    vec![(9, Idle), (18, Idle), (13, Idle), (17, Idle), (5, Idle)],
+                    vec![(19, Idle), (8, Idle), (7, Idle), (25, Idle), (23, Idle)],
+                    vec![(20, Idle), (11, Idle), (10, Idle), (24, Idle), (4, Idle)],
+                    vec![(14, Idle), (21, Idle), (16, Idle), (12, Idle), (6, Idle)],
+                ],
+                vec![
+                    vec![(14, Idle), (21, Idle), (17, Idle), (24, Idle), (4, Idle)],
+                    vec![(10, Idle), (16, Idle), (15, Idle), (9, Idle), (19, Idle)],
+                    vec![(18, Idle), (8, Idle), (23, Idle), (26, Idle), (20, Idle)],
+                    vec![(22, Idle), (11, Idle), (13, Idle), (6, Idle), (5, Idle)],
+                    vec![(2, Idle), (0, Idle), (12, Idle), (3, Idle), (7, Idle)],
+                ],
+            ],
+        };
+        let expected = 4512;
+        assert_eq!(Day04::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = Game {
+            random_numbers: vec![
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1,
+            ],
+            boards: vec![
+                vec![
+                    vec![(22, Idle), (13, Idle), (17, Idle), (11, Idle), (0, Idle)],
+                    vec![(8, Idle), (2, Idle), (23, Idle), (4, Idle), (24, Idle)],
+                    vec![(21, Idle), (9, Idle), (14, Idle), (16, Idle), (7, Idle)],
+                    vec![(6, Idle), (10, Idle), (3, Idle), (18, Idle), (5, Idle)],
+                    vec![(1, Idle), (12, Idle), (20, Idle), (15, Idle), (19, Idle)],
+                ],
+                vec![
+                    vec![(3, Idle), (15, Idle), (0, Idle), (2, Idle), (22, Idle)],
+                    vec![(9, Idle), (18, Idle), (13, Idle), (17, Idle), (5, Idle)],
+                    vec![(19, Idle), (8, Idle), (7, Idle), (25, Idle), (23, Idle)],
+                    vec![(20, Idle), (11, Idle), (10, Idle), (24, Idle), (4, Idle)],
+                    vec![(14, Idle), (21, Idle), (16, Idle), (12, Idle), (6, Idle)],
+                ],
+                vec![
+                    vec![(14, Idle), (21, Idle), (17, Idle), (24, Idle), (4, Idle)],
+                    vec![(10, Idle), (16, Idle), (15, Idle), (9, Idle), (19, Idle)],
+                    vec![(18, Idle), (8, Idle), (23, Idle), (26, Idle), (20, Idle)],
+                    vec![(22, Idle), (11, Idle), (13, Idle), (6, Idle), (5, Idle)],
+                    vec![(2, Idle), (0, Idle), (12, Idle), (3, Idle), (7, Idle)],
+                ],
+            ],
+        };
+        let expected = 1924;
+        assert_eq!(Day04::part2(&data).unwrap(), expected);
+    }
+}
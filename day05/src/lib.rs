@@ -0,0 +1,221 @@
+use aoc_common::{non_empty_lines, InputLine, ParseError, Solution};
+use std::cmp;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line {
+    from: (u32, u32),
+    to: (u32, u32),
+}
+
+impl Line {
+    fn parse(line: InputLine) -> Result<Line, ParseError> {
+        let (from_str, to_str) = line.split_once(line.trimmed(), " -> ")?;
+        let (x1_str, y1_str) = line.split_once(from_str, ",")?;
+        let (x2_str, y2_str) = line.split_once(to_str, ",")?;
+        return Ok(Line {
+            from: (line.parse_number(x1_str)?, line.parse_number(y1_str)?),
+            to: (line.parse_number(x2_str)?, line.parse_number(y2_str)?),
+        });
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input).map(Line::parse).collect();
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        let (matrix_width, matrix_height) = get_matrix_dimension(data);
+        let mut matrix: Vec<Vec<u32>> = vec![vec![0; matrix_width]; matrix_height];
+        let data_it = data.iter();
+        for line in data_it {
+            if line.from.0 == line.to.0 {
+                let y: usize = line.from.0.try_into().unwrap();
+                let min_x: usize = line.from.1.try_into().unwrap();
+                let max_x: usize = line.to.1.try_into().unwrap();
+                let (min_x, max_x) = ensure_order(min_x, max_x);
+                for x in min_x..(max_x + 1) {
+                    matrix[x][y] += 1;
+                }
+            } else if line.from.1 == line.to.1 {
+                let x: usize = line.from.1.try_into().unwrap();
+                let min_y: usize = line.from.0.try_into().unwrap();
+                let max_y: usize = line.to.0.try_into().unwrap();
+                let (min_y, max_y) = ensure_order(min_y, max_y);
+                for y in min_y..(max_y + 1) {
+                    matrix[x][y] += 1;
+                }
+            }
+        }
+        return Ok(count_point_over(&matrix, 1));
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        let (matrix_height, matrix_width) = get_matrix_dimension(data);
+        let mut matrix: Vec<Vec<u32>> = vec![vec![0; matrix_width]; matrix_height];
+        let data_it = data.iter();
+        for line in data_it {
+            if line.from.0 == line.to.0 {
+                let y: usize = line.from.0.try_into().unwrap();
+                let min_x: usize = line.from.1.try_into().unwrap();
+                let max_x: usize = line.to.1.try_into().unwrap();
+                let (min_x, max_x) = ensure_order(min_x, max_x);
+                for x in min_x..(max_x + 1) {
+                    matrix[x][y] += 1;
+                }
+            } else if line.from.1 == line.to.1 {
+                let x: usize = line.from.1.try_into().unwrap();
+                let min_y: usize = line.from.0.try_into().unwrap();
+                let max_y: usize = line.to.0.try_into().unwrap();
+                let (min_y, max_y) = ensure_order(min_y, max_y);
+                for y in min_y..(max_y + 1) {
+                    matrix[x][y] += 1;
+                }
+            } else {
+                let min_x: i32 = line.from.1.try_into().unwrap();
+                let min_y: i32 = line.from.0.try_into().unwrap();
+                let max_x: i32 = line.to.1.try_into().unwrap();
+                let max_y: i32 = line.to.0.try_into().unwrap();
+                let direction_x = max_x - min_x;
+                let direction_y = max_y - min_y;
+                if (direction_x).abs() == (direction_y).abs() {
+                    let max_increment = (direction_x).abs() + 1;
+                    let x_sign: i32 = if min_x <= max_x { 1 } else { -1 };
+                    let y_sign: i32 = if min_y <= max_y { 1 } else { -1 };
+                    for i in 0..max_increment {
+                        let x = min_x + (i * x_sign);
+                        let y = min_y + (i * y_sign);
+                        if x >= 0 && y >= 0 {
+                            let x: usize = x.try_into().unwrap();
+                            let y: usize = y.try_into().unwrap();
+                            matrix[x][y] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        return Ok(count_point_over(&matrix, 1));
+    }
+}
+
+fn get_matrix_dimension(data: &Vec<Line>) -> (usize, usize) {
+    data.iter()
+        .flat_map(|line| vec![line.from, line.to])
+        .map(|(x, y)| (x + 1, y + 1))
+        .fold((0, 0), |(max_x, max_y), (cur_x, cur_y)| {
+            (
+                cmp::max(max_x, cur_x.try_into().unwrap()),
+                cmp::max(max_y, cur_y.try_into().unwrap()),
+            )
+        })
+}
+
+fn count_point_over(matrix: &Vec<Vec<u32>>, over: u32) -> usize {
+    matrix
+        .iter()
+        .flatten()
+        .filter(|point| **point > over)
+        .count()
+}
+
+fn ensure_order<T: Ord>(min: T, max: T) -> (T, T) {
+    if min <= max {
+        (min, max)
+    } else {
+        (max, min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_data() -> Vec<Line> {
+        vec![
+            Line {
+                from: (0, 9),
+                to: (5, 9),
+            },
+            Line {
+                from: (8, 0),
+                to: (0, 8),
+            },
+            Line {
+                from: (9, 4),
+                to: (3, 4),
+            },
+            Line {
+                from: (2, 2),
+                to: (2, 1),
+            },
+            Line {
+                from: (7, 0),
+                to: (7, 4),
+            },
+            Line {
+                from: (6, 4),
+                to: (2, 0),
+            },
+            Line {
+                from: (0, 9),
+                to: (2, 9),
+            },
+            Line {
+                from: (3, 4),
+                to: (1, 4),
+            },
+            Line {
+                from: (0, 0),
+                to: (8, 8),
+            },
+            Line {
+                from: (5, 5),
+                to: (8, 2),
+            },
+        ]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "0,9 -> 5,9
+        8,0 -> 0,8
+        9,4 -> 3,4
+        2,2 -> 2,1
+        7,0 -> 7,4
+        6,4 -> 2,0
+        0,9 -> 2,9
+        3,4 -> 1,4
+        0,0 -> 8,8
+        5,5 -> 8,2";
+
+        let expected = sample_data();
+
+        assert_eq!(Day05::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_matrix_dimension_correctly() {
+        let data = sample_data();
+        assert_eq!(get_matrix_dimension(&data), (10, 10))
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = sample_data();
+        let expected = 5;
+        assert_eq!(Day05::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = sample_data();
+        let expected = 12;
+        assert_eq!(Day05::part2(&data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::{missing_at_end, non_empty_lines, ParseError, Solution};
+use std::collections::HashMap;
+
+type Fish = u8;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Fish>;
+    type Part1Output = u64;
+    type Part2Output = u64;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let line = non_empty_lines(&input)
+            .next()
+            .ok_or_else(|| missing_at_end(&input, "a comma separated list"))?;
+        return line
+            .text
+            .split(',')
+            .filter(|file_fragment| !file_fragment.trim().is_empty())
+            .map(|file_fragment| line.parse_number::<Fish>(file_fragment))
+            .collect();
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        return count_fishes(data, 80);
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        return count_fishes(data, 256);
+    }
+}
+
+fn count_fishes(data: &Vec<Fish>, run_days_count: usize) -> Result<u64, String> {
+    let mut fishes: HashMap<u8, u64> = HashMap::new();
+    (0..9).for_each(|day| {
+        fishes.insert(
+            day,
+            data.iter().filter(|fish| **fish == day).map(|_| 1).sum(),
+        );
+    });
+    for _ in 0..run_days_count {
+        let mut new_fishes: HashMap<u8, u64> = HashMap::new();
+        for day in 0..9 {
+            let fish_count = *(fishes.get(&day).unwrap_or(&0));
+            if day == 0 {
+                let new_fish_count_6 = *(new_fishes.get(&6).unwrap_or(&0));
+                let new_fish_count_8 = *(new_fishes.get(&8).unwrap_or(&0));
+                new_fishes.insert(6, new_fish_count_6 + fish_count);
+                new_fishes.insert(8, new_fish_count_8 + fish_count);
+            } else {
+                let new_fish_count = *(new_fishes.get(&(day - 1)).unwrap_or(&0));
+                new_fishes.insert(day - 1, new_fish_count + fish_count);
+            }
+        }
+        fishes = new_fishes;
+    }
+    return Ok(fishes.values().sum());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_data() -> Vec<Fish> {
+        vec![3, 4, 3, 1, 2]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "3,4,3,1,2\n";
+        let expected = sample_data();
+        assert_eq!(Day06::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_18_correctly() {
+        let data = sample_data();
+        let expected = 26;
+        assert_eq!(count_fishes(&data, 18).unwrap(), expected);
+    }
+    #[test]
+    fn it_should_compute_part1_80_correctly() {
+        let data = sample_data();
+        let expected = 5934;
+        assert_eq!(Day06::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = sample_data();
+        let expected = 26984457539; // 26 984 457 539
+        assert_eq!(Day06::part2(&data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,82 @@
+use aoc_common::{missing_at_end, non_empty_lines, ParseError, Solution};
+type CrabPosition = u32;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<CrabPosition>;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let line = non_empty_lines(&input)
+            .next()
+            .ok_or_else(|| missing_at_end(&input, "a comma separated list"))?;
+        return line
+            .text
+            .split(',')
+            .filter(|file_fragment| !file_fragment.trim().is_empty())
+            .map(|file_fragment| line.parse_number::<CrabPosition>(file_fragment))
+            .collect();
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(compute_best_fuel_cost(data, |move_distance| move_distance));
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        return Ok(compute_best_fuel_cost(data, |move_distance| {
+            move_distance * (move_distance + 1) / 2
+        }));
+    }
+}
+
+fn compute_best_fuel_cost(data: &Vec<CrabPosition>, compute_move_cost: fn(u32) -> u32) -> u32 {
+    let min = *data.iter().min().expect("should have at least one element");
+    let max = *data.iter().max().expect("should have at least one element");
+    (min..max)
+        .map(|destination| {
+            data.iter()
+                .map(|crab_position| {
+                    let step = if destination > *crab_position {
+                        destination - crab_position
+                    } else {
+                        crab_position - destination
+                    };
+                    compute_move_cost(step)
+                })
+                .sum()
+        })
+        .min()
+        .expect("should have at least one element")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_data() -> Vec<CrabPosition> {
+        vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "16,1,2,0,4,2,7,1,2,14\n";
+        let expected = sample_data();
+        assert_eq!(Day07::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = sample_data();
+        let expected = 37;
+        assert_eq!(Day07::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = sample_data();
+        let expected = 168;
+        assert_eq!(Day07::part2(&data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_common::{Grid, ParseError, Solution};
+use std::collections::VecDeque;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+    type Part1Output = u32;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return Grid::parse(&input, "a digit", |c| c.to_digit(10));
+    }
+
+    fn part1(data: &Self::Input) -> Result<Self::Part1Output, String> {
+        let low_points = get_low_points(data);
+        return Ok(low_points.iter().map(|(p, _)| p + 1).sum());
+    }
+
+    fn part2(data: &Self::Input) -> Result<Self::Part2Output, String> {
+        let low_points = get_low_points(data);
+        let mut basins = get_basins_size(data, &low_points);
+        basins.sort();
+        return Ok(basins.iter().rev().take(3).product::<usize>());
+    }
+}
+
+fn get_low_points(data: &Grid<u32>) -> Vec<(u32, (usize, usize))> {
+    let mut low_points: Vec<(u32, (usize, usize))> = Vec::new();
+    for ((i, j), current) in data.iter() {
+        if data
+            .adjacents(i, j)
+            .all(|(ax, ay)| data[(ax, ay)] > *current)
+        {
+            low_points.push((*current, (i, j)));
+        }
+    }
+    return low_points;
+}
+
+fn get_basins_size(data: &Grid<u32>, low_points: &Vec<(u32, (usize, usize))>) -> Vec<usize> {
+    low_points
+        .iter()
+        .map(|point| explore_basin(data, *point))
+        .map(|basin| basin.len())
+        .collect()
+}
+
+fn explore_basin(data: &Grid<u32>, point: (u32, (usize, usize))) -> Vec<(u32, (usize, usize))> {
+    let mut basin: Vec<(u32, (usize, usize))> = vec![point];
+    let mut new: VecDeque<(u32, (usize, usize))> = VecDeque::new();
+    new.push_back(point);
+    while let Some(current_point) = new.pop_front() {
+        let (_, (i, j)) = current_point;
+        if basin.iter().all(|(_, (bx, by))| !(*bx == i && *by == j)) {
+            basin.push(current_point);
+        }
+        data.adjacents(i, j)
+            .map(|(ax, ay)| (data[(ax, ay)], (ax, ay)))
+            .filter(|(height, _)| *height < 9)
+            .filter(|(_, (ax, ay))| {
+                basin
+                    .iter()
+                    .all(|(_, (bx, by))| !(*bx == *ax && *by == *ay))
+            })
+            .for_each(|point| {
+                new.push_back(point);
+            });
+    }
+    return basin;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_data() -> Grid<u32> {
+        Grid::from_rows(vec![
+            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+            vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+            vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+            vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+            vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "2199943210
+        3987894921
+        9856789892
+        8767896789
+        9899965678";
+        let expected = sample_data();
+        assert_eq!(Day09::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let data = sample_data();
+        let expected = 15;
+        assert_eq!(Day09::part1(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_correctly_low_points() {
+        let data = sample_data();
+        let expected = vec![(1, (1, 0)), (0, (9, 0)), (5, (2, 2)), (5, (6, 4))];
+        assert_eq!(get_low_points(&data), expected);
+    }
+
+    #[test]
+    fn it_should_explore_correctly_basin_from_low_point_1() {
+        let data = sample_data();
+        let expected = vec![(1, (1, 0)), (2, (0, 0)), (3, (0, 1))];
+        assert_eq!(explore_basin(&data, (1, (1, 0))), expected);
+    }
+
+    #[test]
+    fn it_should_explore_correctly_basin_from_low_point_2() {
+        let data = sample_data();
+        let expected = vec![
+            (0, (9, 0)),
+            (1, (8, 0)),
+            (1, (9, 1)),
+            (2, (7, 0)),
+            (2, (8, 1)),
+            (2, (9, 2)),
+            (3, (6, 0)),
+            (4, (5, 0)),
+            (4, (6, 1)),
+        ];
+        assert_eq!(explore_basin(&data, (0, (9, 0))), expected);
+    }
+
+    #[test]
+    fn it_should_explore_correctly_basin_from_low_point_3() {
+        let data = sample_data();
+        let expected = vec![
+            (5, (2, 2)),
+            (8, (1, 2)),
+            (8, (2, 1)),
+            (6, (3, 2)),
+            (6, (2, 3)),
+            (7, (1, 3)),
+            (7, (3, 1)),
+            (7, (4, 2)),
+            (7, (3, 3)),
+            (8, (0, 3)),
+            (8, (1, 4)),
+            (8, (4, 1)),
+            (8, (5, 2)),
+            (8, (4, 3)),
+        ];
+        assert_eq!(explore_basin(&data, (5, (2, 2))), expected);
+    }
+
+    #[test]
+    fn it_should_explore_correctly_basin_from_low_point_4() {
+        let data = sample_data();
+        let expected = vec![
+            (5, (6, 4)),
+            (6, (5, 4)),
+            (6, (6, 3)),
+            (6, (7, 4)),
+            (7, (7, 3)),
+            (7, (8, 4)),
+            (8, (7, 2)),
+            (8, (8, 3)),
+            (8, (9, 4)),
+        ];
+        assert_eq!(explore_basin(&data, (5, (6, 4))), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let data = sample_data();
+        let expected = 1134;
+        assert_eq!(Day09::part2(&data).unwrap(), expected);
+    }
+}
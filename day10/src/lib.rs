@@ -0,0 +1,327 @@
+use aoc_common::{non_empty_lines, ParseError, Solution};
+use std::collections::VecDeque;
+
+#[derive(Clone, PartialEq, Debug)]
+enum LineStatus {
+    Valid,
+    Incomplete(Vec<char>),
+    Corrupted(char),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Part1Output = u64;
+    type Part2Output = u64;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input)
+            .map(|line| {
+                let chunks = line.trimmed();
+                line.check_chars(chunks, |c| "()[]{}<>".contains(c), "a bracket")?;
+                Ok(String::from(chunks))
+            })
+            .collect();
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(input
+            .iter()
+            .map(get_line_status)
+            .filter(is_corrupted)
+            .filter_map(get_corrupted_char)
+            .map(corrupted_char_to_score)
+            .sum());
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let mut line_scores: Vec<u64> = input
+            .iter()
+            .map(get_line_status)
+            .filter(is_incomplete)
+            .filter_map(get_incomplete_chars)
+            .map(convert_opening_to_closing)
+            .map(incomplete_line_score)
+            .collect();
+        line_scores.sort();
+        return Ok(line_scores[line_scores.len() / 2]);
+    }
+}
+
+fn is_corrupted(status: &LineStatus) -> bool {
+    matches!(status, LineStatus::Corrupted(_))
+}
+
+fn get_corrupted_char(status: LineStatus) -> Option<char> {
+    match status {
+        LineStatus::Corrupted(c) => Some(c),
+        _ => None,
+    }
+}
+
+fn is_incomplete(status: &LineStatus) -> bool {
+    matches!(status, LineStatus::Incomplete(_))
+}
+
+fn get_incomplete_chars(status: LineStatus) -> Option<Vec<char>> {
+    match status {
+        LineStatus::Incomplete(cc) => Some(cc),
+        _ => None,
+    }
+}
+
+fn get_line_status(line: &String) -> LineStatus {
+    fn is_opening_char(c: char) -> bool {
+        matches!(c, '(' | '[' | '<' | '{')
+    }
+    fn is_matching_chars(opening: char, closing: char) -> bool {
+        match opening {
+            '(' => closing == ')',
+            '[' => closing == ']',
+            '<' => closing == '>',
+            '{' => closing == '}',
+            _ => false,
+        }
+    }
+
+    let mut stack: VecDeque<char> = VecDeque::new();
+
+    for c in line.chars() {
+        if is_opening_char(c) {
+            stack.push_back(c);
+        } else if is_matching_chars(*stack.back().expect("should have one element"), c) {
+            stack.pop_back();
+        } else {
+            return LineStatus::Corrupted(c);
+        }
+    }
+
+    return if stack.is_empty() {
+        LineStatus::Valid
+    } else {
+        LineStatus::Incomplete(stack.into())
+    };
+}
+
+fn corrupted_char_to_score(c: char) -> u64 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+fn missing_char_to_score(c: &char) -> u64 {
+    match c {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => 0,
+    }
+}
+
+fn incomplete_line_score(missing_chars: Vec<char>) -> u64 {
+    missing_chars
+        .iter()
+        .map(missing_char_to_score)
+        .fold(0, |total, cur| total * 5 + cur)
+}
+
+fn convert_opening_to_closing(opening: Vec<char>) -> Vec<char> {
+    fn open_to_close(c: &char) -> Option<char> {
+        match c {
+            '(' => Some(')'),
+            '[' => Some(']'),
+            '<' => Some('>'),
+            '{' => Some('}'),
+            _ => None,
+        }
+    }
+    opening.iter().rev().filter_map(open_to_close).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Vec<String> {
+        vec![
+            String::from("[({(<(())[]>[[{[]{<()<>>"),
+            String::from("[(()[<>])]({[<{<<[]>>("),
+            String::from("{([(<{}[<>[]}>{[]{[(<()>"),
+            String::from("(((({<>}<{<{<>}{[]{[]{}"),
+            String::from("[[<[([]))<([[{}[[()]]]"),
+            String::from("[{[{({}]{}}([{[{{{}}([]"),
+            String::from("{<[[]]>}<{[{[{[]{()[[[]"),
+            String::from("[<(<(<(<{}))><([]([]()"),
+            String::from("<{([([[(<>()){}]>(<<{{"),
+            String::from("<{([{{}}[<[[[<>{}]]]>[]]"),
+        ]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "[({(<(())[]>[[{[]{<()<>>
+            [(()[<>])]({[<{<<[]>>(
+            {([(<{}[<>[]}>{[]{[(<()>
+            (((({<>}<{<{<>}{[]{[]{}
+            [[<[([]))<([[{}[[()]]]
+            [{[{({}]{}}([{[{{{}}([]
+            {<[[]]>}<{[{[{[]{()[[[]
+            [<(<(<(<{}))><([]([]()
+            <{([([[(<>()){}]>(<<{{
+            <{([{{}}[<[[[<>{}]]]>[]]";
+        let expected = sample_input();
+        assert_eq!(Day10::parse_data(input.to_string()).unwrap(), expected);
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 2,
+            text: String::from("é"),
+            expected: String::from("a bracket"),
+        };
+        assert_eq!(Day10::parse_data(String::from("(é)\n")), Err(expected));
+    }
+
+    #[test]
+    fn it_should_detect_correcty_valid_chunk() {
+        assert_eq!(get_line_status(&String::from("()")), LineStatus::Valid);
+        assert_eq!(get_line_status(&String::from("[]")), LineStatus::Valid);
+        assert_eq!(get_line_status(&String::from("([])")), LineStatus::Valid);
+        assert_eq!(
+            get_line_status(&String::from("{()()()}")),
+            LineStatus::Valid
+        );
+        assert_eq!(
+            get_line_status(&String::from("<([{}])>")),
+            LineStatus::Valid
+        );
+        assert_eq!(
+            get_line_status(&String::from("[<>({}){}[([])<>]]")),
+            LineStatus::Valid
+        );
+        assert_eq!(
+            get_line_status(&String::from("(((((((((())))))))))")),
+            LineStatus::Valid
+        );
+    }
+
+    #[test]
+    fn it_should_detect_correcty_corrupted_chunk() {
+        assert_eq!(
+            get_line_status(&String::from("(]")),
+            LineStatus::Corrupted(']')
+        );
+        assert_eq!(
+            get_line_status(&String::from("{()()()>")),
+            LineStatus::Corrupted('>')
+        );
+        assert_eq!(
+            get_line_status(&String::from("(((()))}")),
+            LineStatus::Corrupted('}')
+        );
+        assert_eq!(
+            get_line_status(&String::from("<([]){()}[{}])")),
+            LineStatus::Corrupted(')')
+        );
+        assert_eq!(
+            get_line_status(&String::from("{([(<{}[<>[]}>{[]{[(<()>")),
+            LineStatus::Corrupted('}')
+        );
+        assert_eq!(
+            get_line_status(&String::from("[[<[([]))<([[{}[[()]]]")),
+            LineStatus::Corrupted(')')
+        );
+        assert_eq!(
+            get_line_status(&String::from("[{[{({}]{}}([{[{{{}}([]")),
+            LineStatus::Corrupted(']')
+        );
+        assert_eq!(
+            get_line_status(&String::from("[<(<(<(<{}))><([]([]()")),
+            LineStatus::Corrupted(')')
+        );
+        assert_eq!(
+            get_line_status(&String::from("<{([([[(<>()){}]>(<<{{")),
+            LineStatus::Corrupted('>')
+        );
+    }
+
+    #[test]
+    fn it_should_detect_correcty_incomplete_chunk() {
+        assert_eq!(
+            get_line_status(&String::from("[({(<(())[]>[[{[]{<()<>>")),
+            LineStatus::Incomplete("[({([[{{".chars().collect::<Vec<char>>())
+        );
+        assert_eq!(
+            get_line_status(&String::from("[(()[<>])]({[<{<<[]>>(")),
+            LineStatus::Incomplete("({[<{(".chars().collect::<Vec<char>>())
+        );
+        assert_eq!(
+            get_line_status(&String::from("(((({<>}<{<{<>}{[]{[]{}")),
+            LineStatus::Incomplete("((((<{<{{".chars().collect::<Vec<char>>())
+        );
+        assert_eq!(
+            get_line_status(&String::from("{<[[]]>}<{[{[{[]{()[[[]")),
+            LineStatus::Incomplete("<{[{[{{[[".chars().collect::<Vec<char>>())
+        );
+        assert_eq!(
+            get_line_status(&String::from("<{([{{}}[<[[[<>{}]]]>[]]")),
+            LineStatus::Incomplete("<{([".chars().collect::<Vec<char>>())
+        );
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 26397;
+        assert_eq!(Day10::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_convert_to_closing_correctly() {
+        assert_eq!(
+            convert_opening_to_closing("[({([[{{".chars().collect()),
+            "}}]])})]".chars().collect::<Vec<char>>()
+        );
+        assert_eq!(
+            convert_opening_to_closing("({[<{(".chars().collect()),
+            ")}>]})".chars().collect::<Vec<char>>()
+        );
+        assert_eq!(
+            convert_opening_to_closing("((((<{<{{".chars().collect()),
+            "}}>}>))))".chars().collect::<Vec<char>>()
+        );
+        assert_eq!(
+            convert_opening_to_closing("<{[{[{{[[".chars().collect()),
+            "]]}}]}]}>".chars().collect::<Vec<char>>()
+        );
+        assert_eq!(
+            convert_opening_to_closing("<{([".chars().collect()),
+            "])}>".chars().collect::<Vec<char>>()
+        );
+    }
+
+    #[test]
+    fn it_should_compute_correctly_incomplete_line_score() {
+        let missing = "}}]])})]".chars().collect::<Vec<char>>();
+        assert_eq!(incomplete_line_score(missing), 288957);
+        let missing = ")}>]})".chars().collect::<Vec<char>>();
+        assert_eq!(incomplete_line_score(missing), 5566);
+        let missing = "}}>}>))))".chars().collect::<Vec<char>>();
+        assert_eq!(incomplete_line_score(missing), 1480781);
+        let missing = "]]}}]}]}>".chars().collect::<Vec<char>>();
+        assert_eq!(incomplete_line_score(missing), 995444);
+        let missing = "])}>".chars().collect::<Vec<char>>();
+        assert_eq!(incomplete_line_score(missing), 294);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 288957;
+        assert_eq!(Day10::part2(&input).unwrap(), expected);
+    }
+}
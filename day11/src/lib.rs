@@ -0,0 +1,256 @@
+use aoc_common::{Grid, ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+    type Part1Output = usize;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return Grid::parse(&input, "a digit", |c| {
+            c.to_digit(10).map(|digit| digit as u8)
+        });
+    }
+
+    fn part1(state: &Self::Input) -> Result<Self::Part1Output, String> {
+        return count_flashes(state, 100);
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let expected_flash_count = input.width() * input.height();
+
+        let mut step: u32 = 1;
+        let mut new_state: Self::Input = increase_all_energy(input);
+        while get_flash_count(&new_state) != expected_flash_count {
+            step += 1;
+            new_state = increase_all_energy(&new_state);
+        }
+        return Ok(step);
+    }
+}
+
+fn count_flashes(state: &Grid<u8>, iteration_count: u32) -> Result<usize, String> {
+    if iteration_count == 0 {
+        return Ok(0);
+    }
+    let new_state: Grid<u8> = increase_all_energy(state);
+    let flash_count = get_flash_count(&new_state);
+
+    if let Ok(next_step_flashes_count) = count_flashes(&new_state, iteration_count - 1) {
+        return Ok(flash_count + next_step_flashes_count);
+    } else {
+        return Err(format!(
+            "Did not success to compute step: {}",
+            iteration_count - 1
+        ));
+    }
+}
+
+fn increase_all_energy(state: &Grid<u8>) -> Grid<u8> {
+    fn increase_adjecent_with_flash(local_state: &mut Grid<u8>, i: usize, j: usize) {
+        if local_state[(i, j)] == 11 {
+            // we already apply flash on adjacent
+            return;
+        }
+        let adjacents: Vec<(usize, usize)> = local_state.adjacents_with_diagonals(i, j).collect();
+        adjacents.into_iter().for_each(|pos| {
+            // when over 9, octopus flashing yet, so non necessary to increase energy
+            if local_state[pos] < 10 {
+                local_state[pos] += 1;
+                if local_state[pos] > 9 {
+                    increase_adjecent_with_flash(local_state, pos.0, pos.1);
+                    local_state[pos] = 11;
+                }
+            }
+        });
+    }
+
+    // increase all octopus enery by 1
+    let mut new_state: Grid<u8> = state.map(|x| x + 1);
+
+    // increase energy again for adjacent of flashing octopus
+    let positions: Vec<(usize, usize)> = new_state.positions().collect();
+    for (i, j) in positions {
+        if new_state[(i, j)] > 9 {
+            increase_adjecent_with_flash(&mut new_state, i, j);
+            new_state[(i, j)] = 11;
+        }
+    }
+    return new_state.map(|x| if *x > 9 { 0 } else { *x });
+}
+
+fn get_flash_count(state: &Grid<u8>) -> usize {
+    state.values().filter(|x| **x == 0).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Grid<u8> {
+        Grid::from_rows(vec![
+            vec![5, 4, 8, 3, 1, 4, 3, 2, 2, 3],
+            vec![2, 7, 4, 5, 8, 5, 4, 7, 1, 1],
+            vec![5, 2, 6, 4, 5, 5, 6, 1, 7, 3],
+            vec![6, 1, 4, 1, 3, 3, 6, 1, 4, 6],
+            vec![6, 3, 5, 7, 3, 8, 5, 4, 7, 8],
+            vec![4, 1, 6, 7, 5, 2, 4, 6, 4, 5],
+            vec![2, 1, 7, 6, 8, 4, 1, 7, 2, 1],
+            vec![6, 8, 8, 2, 8, 8, 1, 1, 3, 4],
+            vec![4, 8, 4, 6, 8, 4, 8, 5, 5, 4],
+            vec![5, 2, 8, 3, 7, 5, 1, 5, 2, 6],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "5483143223
+        2745854711
+        5264556173
+        6141336146
+        6357385478
+        4167524645
+        2176841721
+        6882881134
+        4846848554
+        5283751526
+        ";
+        let expected = sample_input();
+        assert_eq!(Day11::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_correctly_flash_count() {
+        let input = Grid::from_rows(vec![
+            vec![3, 4, 5, 4, 3],
+            vec![4, 0, 0, 0, 4],
+            vec![5, 0, 0, 0, 5],
+            vec![4, 0, 0, 0, 4],
+            vec![3, 4, 5, 4, 3],
+        ])
+        .unwrap();
+        let expected = 9;
+        assert_eq!(get_flash_count(&input), expected);
+    }
+
+    #[test]
+    fn it_should_increase_correctly_energy_1() {
+        println!("it_should_increase_correctly_energy_1");
+        let input = Grid::from_rows(vec![
+            vec![5, 4, 8, 3, 1, 4, 3, 2, 2, 3],
+            vec![2, 7, 4, 5, 8, 5, 4, 7, 1, 1],
+            vec![5, 2, 6, 4, 5, 5, 6, 1, 7, 3],
+            vec![6, 1, 4, 1, 3, 3, 6, 1, 4, 6],
+            vec![6, 3, 5, 7, 3, 8, 5, 4, 7, 8],
+            vec![4, 1, 6, 7, 5, 2, 4, 6, 4, 5],
+            vec![2, 1, 7, 6, 8, 4, 1, 7, 2, 1],
+            vec![6, 8, 8, 2, 8, 8, 1, 1, 3, 4],
+            vec![4, 8, 4, 6, 8, 4, 8, 5, 5, 4],
+            vec![5, 2, 8, 3, 7, 5, 1, 5, 2, 6],
+        ])
+        .unwrap();
+        let expected = Grid::from_rows(vec![
+            vec![6, 5, 9, 4, 2, 5, 4, 3, 3, 4],
+            vec![3, 8, 5, 6, 9, 6, 5, 8, 2, 2],
+            vec![6, 3, 7, 5, 6, 6, 7, 2, 8, 4],
+            vec![7, 2, 5, 2, 4, 4, 7, 2, 5, 7],
+            vec![7, 4, 6, 8, 4, 9, 6, 5, 8, 9],
+            vec![5, 2, 7, 8, 6, 3, 5, 7, 5, 6],
+            vec![3, 2, 8, 7, 9, 5, 2, 8, 3, 2],
+            vec![7, 9, 9, 3, 9, 9, 2, 2, 4, 5],
+            vec![5, 9, 5, 7, 9, 5, 9, 6, 6, 5],
+            vec![6, 3, 9, 4, 8, 6, 2, 6, 3, 7],
+        ])
+        .unwrap();
+        assert_eq!(increase_all_energy(&input), expected);
+    }
+
+    #[test]
+    fn it_should_increase_correctly_energy_2() {
+        println!("it_should_increase_correctly_energy_2");
+        let input = Grid::from_rows(vec![
+            vec![6, 5, 9, 4, 2, 5, 4, 3, 3, 4],
+            vec![3, 8, 5, 6, 9, 6, 5, 8, 2, 2],
+            vec![6, 3, 7, 5, 6, 6, 7, 2, 8, 4],
+            vec![7, 2, 5, 2, 4, 4, 7, 2, 5, 7],
+            vec![7, 4, 6, 8, 4, 9, 6, 5, 8, 9],
+            vec![5, 2, 7, 8, 6, 3, 5, 7, 5, 6],
+            vec![3, 2, 8, 7, 9, 5, 2, 8, 3, 2],
+            vec![7, 9, 9, 3, 9, 9, 2, 2, 4, 5],
+            vec![5, 9, 5, 7, 9, 5, 9, 6, 6, 5],
+            vec![6, 3, 9, 4, 8, 6, 2, 6, 3, 7],
+        ])
+        .unwrap();
+        let expected = Grid::from_rows(vec![
+            vec![8, 8, 0, 7, 4, 7, 6, 5, 5, 5],
+            vec![5, 0, 8, 9, 0, 8, 7, 0, 5, 4],
+            vec![8, 5, 9, 7, 8, 8, 9, 6, 0, 8],
+            vec![8, 4, 8, 5, 7, 6, 9, 6, 0, 0],
+            vec![8, 7, 0, 0, 9, 0, 8, 8, 0, 0],
+            vec![6, 6, 0, 0, 0, 8, 8, 9, 8, 9],
+            vec![6, 8, 0, 0, 0, 0, 5, 9, 4, 3],
+            vec![0, 0, 0, 0, 0, 0, 7, 4, 5, 6],
+            vec![9, 0, 0, 0, 0, 0, 0, 8, 7, 6],
+            vec![8, 7, 0, 0, 0, 0, 6, 8, 4, 8],
+        ])
+        .unwrap();
+        let actual = increase_all_energy(&input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_increase_correctly_energy_3() {
+        println!("it_should_increase_correctly_energy_3");
+        let input = Grid::from_rows(vec![
+            vec![8, 8, 0, 7, 4, 7, 6, 5, 5, 5],
+            vec![5, 0, 8, 9, 0, 8, 7, 0, 5, 4],
+            vec![8, 5, 9, 7, 8, 8, 9, 6, 0, 8],
+            vec![8, 4, 8, 5, 7, 6, 9, 6, 0, 0],
+            vec![8, 7, 0, 0, 9, 0, 8, 8, 0, 0],
+            vec![6, 6, 0, 0, 0, 8, 8, 9, 8, 9],
+            vec![6, 8, 0, 0, 0, 0, 5, 9, 4, 3],
+            vec![0, 0, 0, 0, 0, 0, 7, 4, 5, 6],
+            vec![9, 0, 0, 0, 0, 0, 0, 8, 7, 6],
+            vec![8, 7, 0, 0, 0, 0, 6, 8, 4, 8],
+        ])
+        .unwrap();
+        let expected = Grid::from_rows(vec![
+            vec![0, 0, 5, 0, 9, 0, 0, 8, 6, 6],
+            vec![8, 5, 0, 0, 8, 0, 0, 5, 7, 5],
+            vec![9, 9, 0, 0, 0, 0, 0, 0, 3, 9],
+            vec![9, 7, 0, 0, 0, 0, 0, 0, 4, 1],
+            vec![9, 9, 3, 5, 0, 8, 0, 0, 6, 3],
+            vec![7, 7, 1, 2, 3, 0, 0, 0, 0, 0],
+            vec![7, 9, 1, 1, 2, 5, 0, 0, 0, 9],
+            vec![2, 2, 1, 1, 1, 3, 0, 0, 0, 0],
+            vec![0, 4, 2, 1, 1, 2, 5, 0, 0, 0],
+            vec![0, 0, 2, 1, 1, 1, 9, 0, 0, 0],
+        ])
+        .unwrap();
+        assert_eq!(increase_all_energy(&input), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_10() {
+        println!("it_should_compute_part1_correctly_10");
+        let input = sample_input();
+        let expected = 204;
+        assert_eq!(count_flashes(&input, 10).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_100() {
+        let input = sample_input();
+        let expected = 1656;
+        assert_eq!(Day11::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 195;
+        assert_eq!(Day11::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,359 @@
+use aoc_common::{missing_at_end, non_empty_lines, ParseError, Solution};
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, Debug)]
+enum Node {
+    Small(String, Vec<String>),
+    Big(String, Vec<String>),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Graph {
+    nodes: HashMap<String, Node>,
+    start: Node,
+}
+
+type Path = Vec<String>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let connections: Vec<(String, String)> = non_empty_lines(&input)
+            .map(|line| line.split_once(line.trimmed(), "-"))
+            .map(|splitted| splitted.map(|(a, b)| (String::from(a), String::from(b))))
+            .collect::<Result<Vec<(String, String)>, ParseError>>()?;
+
+        let mut nodes_map: HashMap<String, Vec<String>> = HashMap::new();
+        for (from, to) in connections.iter() {
+            if *from != "end" && *to != "start" {
+                if let Some(previous_to_list) = nodes_map.get_mut(from) {
+                    previous_to_list.push(to.clone());
+                } else {
+                    let to_list: Vec<String> = vec![to.clone()];
+                    nodes_map.insert(from.clone(), to_list);
+                }
+            }
+            if *to != "end" && *from != "start" {
+                if let Some(previous_to_list) = nodes_map.get_mut(to) {
+                    previous_to_list.push(from.clone());
+                } else {
+                    let to_list: Vec<String> = vec![from.clone()];
+                    nodes_map.insert(to.clone(), to_list);
+                }
+            }
+        }
+
+        let nodes: Vec<Node> = nodes_map
+            .iter()
+            .map(|(from, to)| {
+                if is_big(from) {
+                    Node::Big(from.clone(), to.clone())
+                } else {
+                    Node::Small(from.clone(), to.clone())
+                }
+            })
+            .collect();
+
+        let nodes: HashMap<String, Node> = nodes
+            .iter()
+            .map(|node| {
+                let name = match node {
+                    Node::Small(node_name, _) => node_name,
+                    Node::Big(node_name, _) => node_name,
+                };
+                return (name.clone(), node.clone());
+            })
+            .collect();
+
+        let start = nodes
+            .get(&String::from("start"))
+            .ok_or_else(|| missing_at_end(&input, "a connection from start"))?
+            .clone();
+
+        return Ok(Graph { nodes, start });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let all_path = get_all_path_1(input);
+        return Ok(all_path.len());
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let all_path = get_all_path_2(input);
+        return Ok(all_path.len());
+    }
+}
+
+fn is_big(s: &String) -> bool {
+    s.to_ascii_uppercase() == *s
+}
+
+fn get_all_path_1(graph: &Graph) -> Vec<Path> {
+    fn explore(graph: &Graph, path: &Path, node_name: String) -> Vec<Path> {
+        if node_name == "end" {
+            return vec![[path.clone(), vec![String::from("end")]].concat()];
+        }
+
+        return match graph.nodes.get(&node_name) {
+            Some(Node::Big(_, next_nodes)) => next_nodes
+                .iter()
+                .map(|n| {
+                    explore(
+                        graph,
+                        &[path.clone(), vec![node_name.clone()]].concat(),
+                        n.clone(),
+                    )
+                })
+                .fold(vec![], |acc, cur| [acc, cur].concat()),
+            Some(Node::Small(_, next_nodes)) => {
+                if path.contains(&node_name) {
+                    vec![]
+                } else {
+                    next_nodes
+                        .iter()
+                        .map(|n| {
+                            explore(
+                                graph,
+                                &[path.clone(), vec![node_name.clone()]].concat(),
+                                n.clone(),
+                            )
+                        })
+                        .fold(vec![], |acc, cur| [acc, cur].concat())
+                }
+            }
+            None => vec![],
+        };
+    }
+    return explore(graph, &vec![], String::from("start"));
+}
+
+fn get_all_path_2(graph: &Graph) -> Vec<Path> {
+    fn explore(
+        graph: &Graph,
+        path: &Path,
+        node_name: String,
+        small_twice: Option<String>,
+    ) -> Vec<Path> {
+        if node_name == "end" {
+            return vec![[path.clone(), vec![String::from("end")]].concat()];
+        }
+
+        return match graph.nodes.get(&node_name) {
+            Some(Node::Big(_, next_nodes)) => next_nodes
+                .iter()
+                .map(|n| {
+                    explore(
+                        graph,
+                        &[path.clone(), vec![node_name.clone()]].concat(),
+                        n.clone(),
+                        small_twice.clone(),
+                    )
+                })
+                .fold(vec![], |acc, cur| [acc, cur].concat()),
+            Some(Node::Small(_, next_nodes)) => {
+                if path.contains(&node_name) {
+                    if small_twice.is_some() {
+                        vec![]
+                    } else {
+                        next_nodes
+                            .iter()
+                            .map(|n| {
+                                explore(
+                                    graph,
+                                    &[path.clone(), vec![node_name.clone()]].concat(),
+                                    n.clone(),
+                                    Some(node_name.clone()),
+                                )
+                            })
+                            .fold(vec![], |acc, cur| [acc, cur].concat())
+                    }
+                } else {
+                    next_nodes
+                        .iter()
+                        .map(|n| {
+                            explore(
+                                graph,
+                                &[path.clone(), vec![node_name.clone()]].concat(),
+                                n.clone(),
+                                small_twice.clone(),
+                            )
+                        })
+                        .fold(vec![], |acc, cur| [acc, cur].concat())
+                }
+            }
+            None => vec![],
+        };
+    }
+    return explore(graph, &vec![], String::from("start"), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Graph {
+        let mut graph = Graph {
+            nodes: HashMap::new(),
+            start: Node::Small(
+                String::from("start"),
+                vec![String::from("A"), String::from("b")],
+            ),
+        };
+        graph.nodes.insert(
+            String::from("start"),
+            Node::Small(
+                String::from("start"),
+                vec![String::from("A"), String::from("b")],
+            ),
+        );
+        graph.nodes.insert(
+            String::from("A"),
+            Node::Big(
+                String::from("A"),
+                vec![String::from("c"), String::from("b"), String::from("end")],
+            ),
+        );
+        graph.nodes.insert(
+            String::from("b"),
+            Node::Small(
+                String::from("b"),
+                vec![String::from("A"), String::from("d"), String::from("end")],
+            ),
+        );
+        graph.nodes.insert(
+            String::from("c"),
+            Node::Small(String::from("c"), vec![String::from("A")]),
+        );
+        graph.nodes.insert(
+            String::from("d"),
+            Node::Small(String::from("d"), vec![String::from("b")]),
+        );
+        return graph;
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "start-A
+        start-b
+        A-c
+        A-b
+        b-d
+        A-end
+        b-end";
+        let expected = sample_input();
+        assert_eq!(Day12::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_small() {
+        let input = sample_input();
+        let expected = 10;
+        assert_eq!(Day12::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_middle() {
+        let input = Day12::parse_data(String::from(
+            "dc-end
+        HN-start
+        start-kj
+        dc-start
+        dc-HN
+        LN-dc
+        HN-end
+        kj-sa
+        kj-HN
+        kj-dc",
+        ))
+        .unwrap();
+        let expected = 19;
+        assert_eq!(Day12::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_larger() {
+        let input = Day12::parse_data(String::from(
+            "fs-end
+        he-DX
+        fs-he
+        start-DX
+        pj-DX
+        end-zg
+        zg-sl
+        zg-pj
+        pj-he
+        RW-he
+        fs-DX
+        pj-RW
+        zg-RW
+        start-pj
+        he-WI
+        zg-he
+        pj-fs
+        start-RW
+        ",
+        ))
+        .unwrap();
+        let expected = 226;
+        assert_eq!(Day12::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_small() {
+        let input = sample_input();
+        let expected = 36;
+        assert_eq!(Day12::part2(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_middle() {
+        let input = Day12::parse_data(String::from(
+            "dc-end
+        HN-start
+        start-kj
+        dc-start
+        dc-HN
+        LN-dc
+        HN-end
+        kj-sa
+        kj-HN
+        kj-dc",
+        ))
+        .unwrap();
+        let expected = 103;
+        assert_eq!(Day12::part2(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_larger() {
+        let input = Day12::parse_data(String::from(
+            "fs-end
+        he-DX
+        fs-he
+        start-DX
+        pj-DX
+        end-zg
+        zg-sl
+        zg-pj
+        pj-he
+        RW-he
+        fs-DX
+        pj-RW
+        zg-RW
+        start-pj
+        he-WI
+        zg-he
+        pj-fs
+        start-RW
+        ",
+        ))
+        .unwrap();
+        let expected = 3509;
+        assert_eq!(Day12::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,294 @@
+use aoc_common::{input_lines, missing_at_end, Grid, ParseError, Solution};
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Dot,
+    No,
+}
+
+impl Mark {
+    fn is_dot(self) -> bool {
+        match self {
+            Mark::Dot => true,
+            Mark::No => false,
+        }
+    }
+}
+
+impl core::fmt::Debug for Mark {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Mark::Dot => write!(f, "█"),
+            Mark::No => write!(f, "░"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Instruction {
+    X(usize),
+    Y(usize),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Input {
+    page: Grid<Mark>,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+    type Part1Output = usize;
+    type Part2Output = String;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut it = input_lines(&input);
+        let mut dot_position: Vec<(usize, usize)> = Vec::new();
+        for line in it.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (x, y) = line.split_once(line.trimmed(), ",")?;
+            dot_position.push((line.parse_number(x)?, line.parse_number(y)?));
+        }
+        let max_x: usize = *dot_position
+            .iter()
+            .map(|(x, _)| x)
+            .max()
+            .ok_or_else(|| missing_at_end(&input, "a dot position"))?;
+        let max_y: usize = *dot_position.iter().map(|(_, y)| y).max().unwrap();
+        let mut page: Grid<Mark> = Grid::new(max_x + 1, max_y + 1, Mark::No);
+        dot_position
+            .iter()
+            .for_each(|position| page[*position] = Mark::Dot);
+
+        // folds are replayed on the dots, each one having to cut the page in two non-empty halves
+        // without going through a dot
+        let (mut width, mut height) = (page.width(), page.height());
+        let mut instructions: Vec<Instruction> = Vec::new();
+        for line in it {
+            if line.is_empty() {
+                break;
+            }
+            let (axis, fold_position) = line.split_once(line.trimmed(), "=")?;
+            let instruction = match axis {
+                "fold along x" => Instruction::X(line.parse_number(fold_position)?),
+                "fold along y" => Instruction::Y(line.parse_number(fold_position)?),
+                _ => return Err(line.unexpected(axis, "fold along x or fold along y")),
+            };
+            let (position, size) = match instruction {
+                Instruction::X(x) => (x, width),
+                Instruction::Y(y) => (y, height),
+            };
+            if position == 0 || position + 1 >= size {
+                let expected = match size {
+                    0..=2 => String::from("no more fold along this axis"),
+                    _ => format!("a fold between 1 and {}", size - 2),
+                };
+                return Err(line.unexpected(fold_position, &expected));
+            }
+            let on_fold = |(x, y): &(usize, usize)| match instruction {
+                Instruction::X(fold_x) => *x == fold_x,
+                Instruction::Y(fold_y) => *y == fold_y,
+            };
+            if dot_position.iter().any(on_fold) {
+                return Err(line.unexpected(fold_position, "a fold line without dots"));
+            }
+            dot_position = dot_position
+                .iter()
+                .filter_map(|dot| fold_dot(*dot, instruction))
+                .collect();
+            match instruction {
+                Instruction::X(x) => width = x,
+                Instruction::Y(y) => height = y,
+            }
+            instructions.push(instruction);
+        }
+        if instructions.is_empty() {
+            return Err(missing_at_end(&input, "a fold instruction"));
+        }
+
+        return Ok(Input { page, instructions });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let new_matrix = fold(&input.page, input.instructions[0]);
+        return Ok(count_dot(&new_matrix));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let mut new_matrix: Grid<Mark> = input.page.clone();
+        input
+            .instructions
+            .iter()
+            .for_each(|inst| new_matrix = fold(&new_matrix, *inst));
+        return Ok(new_matrix.render(|mark| format!("{:?}", mark)));
+    }
+}
+
+fn fold(matrix: &Grid<Mark>, instruction: Instruction) -> Grid<Mark> {
+    return match instruction {
+        Instruction::X(x_fold) => {
+            let (matrix_left, matrix_right) = cut_matrix_x(matrix, x_fold);
+            let matrix_right = matrix_right.mirror_x();
+            merge_dot_matrixes_from_bottom_right(&matrix_left, &matrix_right)
+        }
+        Instruction::Y(y_fold) => {
+            let (matrix_top, matrix_bottom) = cut_matrix_y(matrix, y_fold);
+            let matrix_bottom = matrix_bottom.mirror_y();
+            merge_dot_matrixes_from_bottom_right(&matrix_top, &matrix_bottom)
+        }
+    };
+}
+
+/// Where a dot off the fold line lands, if it stays on the page.
+fn fold_dot((x, y): (usize, usize), instruction: Instruction) -> Option<(usize, usize)> {
+    return match instruction {
+        Instruction::X(fold_x) if x > fold_x => (2 * fold_x).checked_sub(x).map(|x| (x, y)),
+        Instruction::Y(fold_y) if y > fold_y => (2 * fold_y).checked_sub(y).map(|y| (x, y)),
+        _ => Some((x, y)),
+    };
+}
+
+fn cut_matrix_x<T: Clone>(matrix: &Grid<T>, x: usize) -> (Grid<T>, Grid<T>) {
+    (
+        matrix.sub_grid(0, 0, x, matrix.height()),
+        matrix.sub_grid(x + 1, 0, matrix.width(), matrix.height()),
+    )
+}
+
+fn cut_matrix_y<T: Clone>(matrix: &Grid<T>, y: usize) -> (Grid<T>, Grid<T>) {
+    (
+        matrix.sub_grid(0, 0, matrix.width(), y),
+        matrix.sub_grid(0, y + 1, matrix.width(), matrix.height()),
+    )
+}
+
+fn merge_dot_matrixes_from_bottom_right(matrix1: &Grid<Mark>, matrix2: &Grid<Mark>) -> Grid<Mark> {
+    let mut matrix: Grid<Mark> = Grid::new(matrix1.width(), matrix1.height(), Mark::No);
+    let m1_max_x = matrix1.width() - 1;
+    let m1_max_y = matrix1.height() - 1;
+    let m2_max_x = matrix2.width() - 1;
+    let m2_max_y = matrix2.height() - 1;
+    let folded_x = [m1_max_x, m2_max_x];
+    let folded_y = [m1_max_y, m2_max_y];
+    let folded_x = folded_x.iter().min().unwrap();
+    let folded_y = folded_y.iter().min().unwrap();
+    for j in 0..(folded_y + 1) {
+        for i in 0..(folded_x + 1) {
+            if matrix1[(m1_max_x - i, m1_max_y - j)].is_dot()
+                || matrix2[(m2_max_x - i, m2_max_y - j)].is_dot()
+            {
+                matrix[(m1_max_x - i, m1_max_y - j)] = Mark::Dot;
+            } else {
+                matrix[(m1_max_x - i, m1_max_y - j)] = Mark::No;
+            }
+        }
+    }
+    return matrix;
+}
+
+fn count_dot(matrix: &Grid<Mark>) -> usize {
+    matrix.values().filter(|mark| mark.is_dot()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Instruction::*;
+    use super::Mark::*;
+    use super::*;
+
+    fn sample_input() -> Input {
+        Input {
+            page: Grid::from_rows(vec![
+                vec![No, No, No, Dot, No, No, Dot, No, No, Dot, No],
+                vec![No, No, No, No, Dot, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![Dot, No, No, No, No, No, No, No, No, No, No],
+                vec![No, No, No, Dot, No, No, No, No, Dot, No, Dot],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, No, No, No, No, No],
+                vec![No, Dot, No, No, No, No, Dot, No, Dot, Dot, No],
+                vec![No, No, No, No, Dot, No, No, No, No, No, No],
+                vec![No, No, No, No, No, No, Dot, No, No, No, Dot],
+                vec![Dot, No, No, No, No, No, No, No, No, No, No],
+                vec![Dot, No, Dot, No, No, No, No, No, No, No, No],
+            ])
+            .unwrap(),
+            instructions: vec![Y(7), X(5)],
+        }
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "6,10
+        0,14
+        9,10
+        0,3
+        10,4
+        4,11
+        6,0
+        6,12
+        4,1
+        0,13
+        10,12
+        3,4
+        3,0
+        8,4
+        1,10
+        2,14
+        8,10
+        9,0
+        
+        fold along y=7
+        fold along x=5";
+        let expected = sample_input();
+        assert_eq!(Day13::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_reject_folds_off_the_page_or_through_dots() {
+        let expected = ParseError::UnexpectedToken {
+            line: 4,
+            column: 14,
+            text: String::from("0"),
+            expected: String::from("a fold between 1 and 1"),
+        };
+        let input = "0,0\n2,2\n\nfold along y=0\n";
+        assert_eq!(Day13::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::UnexpectedToken {
+            line: 5,
+            column: 14,
+            text: String::from("1"),
+            expected: String::from("a fold line without dots"),
+        };
+        let input = "0,0\n1,1\n2,2\n\nfold along x=1\n";
+        assert_eq!(Day13::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::UnexpectedToken {
+            line: 5,
+            column: 14,
+            text: String::from("2"),
+            expected: String::from("a fold between 1 and 1"),
+        };
+        let input = "0,0\n0,6\n\nfold along y=3\nfold along y=2\n";
+        assert_eq!(Day13::parse_data(input.to_string()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 17;
+        assert_eq!(Day13::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = "█████\n█░░░█\n█░░░█\n█░░░█\n█████\n░░░░░\n░░░░░";
+        assert_eq!(Day13::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,281 @@
+use aoc_common::{missing_at_end, non_empty_lines, ParseError, Solution};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Input {
+    template: Vec<char>,
+    insertion_map: HashMap<Vec<char>, char>,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+    type Part1Output = BigUint;
+    type Part2Output = BigUint;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut it = non_empty_lines(&input);
+
+        let template: Vec<char> = it
+            .next()
+            .ok_or_else(|| missing_at_end(&input, "a polymer template"))?
+            .trimmed()
+            .chars()
+            .collect();
+
+        let mut insertion_map: HashMap<Vec<char>, char> = HashMap::new();
+        for line in it {
+            let (from, to): (&str, &str) = line.split_once(line.trimmed(), " -> ")?;
+            if from.chars().count() != 2 {
+                return Err(line.unexpected(from, "a pair of elements"));
+            }
+            let mut to_chars = to.chars();
+            match (to_chars.next(), to_chars.next()) {
+                (Some(element), None) => insertion_map.insert(from.chars().collect(), element),
+                _ => return Err(line.unexpected(to, "a single element")),
+            };
+        }
+
+        return Ok(Input {
+            template,
+            insertion_map,
+        });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(most_minus_least_common(input, 10));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        return Ok(most_minus_least_common(input, 40));
+    }
+}
+
+fn most_minus_least_common(input: &Input, steps: usize) -> BigUint {
+    let mut polymer = Polymer::new(&input.template, &input.insertion_map);
+    polymer.run(steps);
+    let occurences = polymer.element_counts();
+    match (occurences.values().max(), occurences.values().min()) {
+        (Some(max), Some(min)) => max - min,
+        _ => BigUint::default(),
+    }
+}
+
+/// A polymer growing under a set of insertion rules, tracked as the number of each pair of
+/// adjacent elements rather than as a sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polymer<'a> {
+    insertion_map: &'a HashMap<Vec<char>, char>,
+    pairs: HashMap<(char, char), BigUint>,
+    /// Elements at both ends, the only ones not shared by two pairs.
+    ends: Option<(char, char)>,
+    step: usize,
+}
+
+impl<'a> Polymer<'a> {
+    pub fn new(template: &[char], insertion_map: &'a HashMap<Vec<char>, char>) -> Self {
+        let mut pairs: HashMap<(char, char), BigUint> = HashMap::new();
+        for window in template.windows(2) {
+            *pairs.entry((window[0], window[1])).or_default() += 1u8;
+        }
+        return Polymer {
+            insertion_map,
+            pairs,
+            ends: template.first().zip(template.last()).map(|(a, b)| (*a, *b)),
+            step: 0,
+        };
+    }
+
+    /// Number of steps applied since the template.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Inserts an element between every pair having a rule, the others staying as they are.
+    pub fn grow(&mut self) {
+        let mut pairs: HashMap<(char, char), BigUint> = HashMap::new();
+        for ((before, after), count) in self.pairs.drain() {
+            match self.insertion_map.get(&vec![before, after]) {
+                Some(inserted) => {
+                    *pairs.entry((before, *inserted)).or_default() += &count;
+                    *pairs.entry((*inserted, after)).or_default() += count;
+                }
+                None => *pairs.entry((before, after)).or_default() += count,
+            }
+        }
+        self.pairs = pairs;
+        self.step += 1;
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.grow();
+        }
+    }
+
+    /// Occurrences of each element. Every element but the ends is in two pairs, so the ends are
+    /// counted once more before halving.
+    pub fn element_counts(&self) -> HashMap<char, BigUint> {
+        let mut counts: HashMap<char, BigUint> = HashMap::new();
+        if let Some((first, last)) = self.ends {
+            *counts.entry(first).or_default() += 1u8;
+            *counts.entry(last).or_default() += 1u8;
+        }
+        for ((before, after), count) in &self.pairs {
+            *counts.entry(*before).or_default() += count;
+            *counts.entry(*after).or_default() += count;
+        }
+        for count in counts.values_mut() {
+            *count >>= 1;
+        }
+        return counts;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Input {
+        let mut insertion_map: HashMap<Vec<char>, char> = HashMap::new();
+        insertion_map.insert(vec!['C', 'H'], 'B');
+        insertion_map.insert(vec!['H', 'H'], 'N');
+        insertion_map.insert(vec!['C', 'B'], 'H');
+        insertion_map.insert(vec!['N', 'H'], 'C');
+        insertion_map.insert(vec!['H', 'B'], 'C');
+        insertion_map.insert(vec!['H', 'C'], 'B');
+        insertion_map.insert(vec!['H', 'N'], 'C');
+        insertion_map.insert(vec!['N', 'N'], 'C');
+        insertion_map.insert(vec!['B', 'H'], 'H');
+        insertion_map.insert(vec!['N', 'C'], 'B');
+        insertion_map.insert(vec!['N', 'B'], 'B');
+        insertion_map.insert(vec!['B', 'N'], 'B');
+        insertion_map.insert(vec!['B', 'B'], 'N');
+        insertion_map.insert(vec!['B', 'C'], 'B');
+        insertion_map.insert(vec!['C', 'C'], 'N');
+        insertion_map.insert(vec!['C', 'N'], 'C');
+        Input {
+            template: vec!['N', 'N', 'C', 'B'],
+            insertion_map,
+        }
+    }
+
+    fn element_counts(input: &Input, steps: usize) -> HashMap<char, BigUint> {
+        let mut polymer = Polymer::new(&input.template, &input.insertion_map);
+        polymer.run(steps);
+        assert_eq!(polymer.step(), steps);
+        return polymer.element_counts();
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "NNCB
+
+        CH -> B
+        HH -> N
+        CB -> H
+        NH -> C
+        HB -> C
+        HC -> B
+        HN -> C
+        NN -> C
+        BH -> H
+        NC -> B
+        NB -> B
+        BN -> B
+        BB -> N
+        BC -> B
+        CC -> N
+        CN -> C";
+        let expected = sample_input();
+        assert_eq!(Day14::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_insertions_correctly_1() {
+        let input = sample_input();
+        let mut expected: HashMap<char, BigUint> = HashMap::new();
+        expected.insert('N', BigUint::from(2u8));
+        expected.insert('B', BigUint::from(2u8));
+        expected.insert('C', BigUint::from(2u8));
+        expected.insert('H', BigUint::from(1u8));
+        assert_eq!(element_counts(&input, 1), expected);
+    }
+
+    #[test]
+    fn it_should_compute_insertions_correctly_2() {
+        let input = sample_input();
+        let mut expected: HashMap<char, BigUint> = HashMap::new();
+        expected.insert('N', BigUint::from(2u8));
+        expected.insert('B', BigUint::from(6u8));
+        expected.insert('C', BigUint::from(4u8));
+        expected.insert('H', BigUint::from(1u8));
+        assert_eq!(element_counts(&input, 2), expected);
+    }
+
+    #[test]
+    fn it_should_compute_insertions_correctly_3() {
+        let input = sample_input();
+        let mut expected: HashMap<char, BigUint> = HashMap::new();
+        expected.insert('N', BigUint::from(5u8));
+        expected.insert('B', BigUint::from(11u8));
+        expected.insert('C', BigUint::from(5u8));
+        expected.insert('H', BigUint::from(4u8));
+        assert_eq!(element_counts(&input, 3), expected);
+    }
+
+    #[test]
+    fn it_should_compute_insertions_correctly_4() {
+        let input = sample_input();
+        let mut expected: HashMap<char, BigUint> = HashMap::new();
+        expected.insert('N', BigUint::from(11u8));
+        expected.insert('B', BigUint::from(23u8));
+        expected.insert('C', BigUint::from(10u8));
+        expected.insert('H', BigUint::from(5u8));
+        assert_eq!(element_counts(&input, 4), expected);
+    }
+
+    #[test]
+    fn it_should_keep_rule_sets_apart() {
+        let input = sample_input();
+        let mut other_rules = input.insertion_map.clone();
+        other_rules.insert(vec!['N', 'N'], 'H');
+        let other = Input {
+            template: input.template.clone(),
+            insertion_map: other_rules,
+        };
+        assert_eq!(element_counts(&input, 1)[&'C'], BigUint::from(2u8));
+        assert_eq!(element_counts(&other, 1)[&'C'], BigUint::from(1u8));
+        assert_eq!(element_counts(&input, 1)[&'C'], BigUint::from(2u8));
+    }
+
+    #[test]
+    fn it_should_count_elements_after_thousands_of_steps() {
+        let input = sample_input();
+        let counts = element_counts(&input, 3000);
+        // Every pair has a rule, so the length doubles minus one at each step.
+        let length: BigUint = counts.values().sum();
+        assert_eq!(length, (BigUint::from(3u8) << 3000u32) + 1u8);
+        assert!(counts[&'B'].bits() > 64);
+        let mut unruled = Polymer::new(&['X', 'Y', 'X'], &input.insertion_map);
+        unruled.run(2000);
+        let expected = HashMap::from([('X', BigUint::from(2u8)), ('Y', BigUint::from(1u8))]);
+        assert_eq!(unruled.element_counts(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = BigUint::from(1588u16);
+        assert_eq!(Day14::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = BigUint::from(2188189693529u64);
+        assert_eq!(Day14::part2(&input).unwrap(), expected);
+    }
+}
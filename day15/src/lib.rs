@@ -0,0 +1,696 @@
+use aoc_common::{Grid, ParseError, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u32>;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return Grid::parse(&input, "a digit", |c| c.to_digit(10));
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        return total_risk(input);
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        return total_risk(&TiledCave::new(input, 5));
+    }
+}
+
+pub type Position = (usize, usize);
+
+/// Positions from the start to the goal, each with the risk of the route up to it.
+pub type Route = Vec<(Position, u32)>;
+
+/// Risk levels of a rectangular cave.
+pub trait Cave {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn risk(&self, position: Position) -> u32;
+
+    /// Lowest risk of the cave, looking at every cell unless implemented.
+    fn min_risk(&self) -> u32 {
+        (0..self.height())
+            .flat_map(|y| (0..self.width()).map(move |x| (x, y)))
+            .map(|position| self.risk(position))
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn contains(&self, position: Position) -> bool {
+        position.0 < self.width() && position.1 < self.height()
+    }
+}
+
+impl Cave for Grid<u32> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn risk(&self, position: Position) -> u32 {
+        self[position]
+    }
+
+    fn min_risk(&self) -> u32 {
+        self.values().min().copied().unwrap_or(0)
+    }
+}
+
+/// A cave repeated `multiplicator` times in both directions, each tile to the right or below
+/// having its risks increased by 1, wrapping back to 1 after 9. Risks are computed on access.
+pub struct TiledCave<'a> {
+    base: &'a Grid<u32>,
+    multiplicator: usize,
+}
+
+impl<'a> TiledCave<'a> {
+    pub fn new(base: &'a Grid<u32>, multiplicator: usize) -> Self {
+        TiledCave {
+            base,
+            multiplicator,
+        }
+    }
+}
+
+impl Cave for TiledCave<'_> {
+    fn width(&self) -> usize {
+        self.base.width() * self.multiplicator
+    }
+
+    fn height(&self) -> usize {
+        self.base.height() * self.multiplicator
+    }
+
+    fn risk(&self, (x, y): Position) -> u32 {
+        let (tile_x, tile_y) = (x / self.base.width(), y / self.base.height());
+        let base = self.base[(x % self.base.width(), y % self.base.height())];
+        let increment = ((tile_x + tile_y) % 9) as u32;
+        (base + increment + 8) % 9 + 1
+    }
+
+    fn min_risk(&self) -> u32 {
+        // Increments only matter modulo 9.
+        let increments = (2 * self.multiplicator).saturating_sub(1).min(9) as u32;
+        self.base
+            .values()
+            .flat_map(|base| (0..increments).map(move |increment| (base + increment + 8) % 9 + 1))
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+/// Moves allowed from a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    /// To the 4 cells sharing an edge.
+    Orthogonal,
+    /// To the 8 cells touching it.
+    Diagonal,
+    /// Like a chess knight.
+    Knight,
+}
+
+impl Movement {
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Movement::Orthogonal => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Movement::Diagonal => &[
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+            ],
+            Movement::Knight => &[
+                (1, -2),
+                (2, -1),
+                (2, 1),
+                (1, 2),
+                (-1, 2),
+                (-2, 1),
+                (-2, -1),
+                (-1, -2),
+            ],
+        }
+    }
+
+    /// Fewest moves between two positions, ignoring the edges of the cave.
+    fn min_moves(&self, from: Position, to: Position) -> u32 {
+        let dx = from.0.abs_diff(to.0) as u32;
+        let dy = from.1.abs_diff(to.1) as u32;
+        match self {
+            Movement::Orthogonal => dx + dy,
+            Movement::Diagonal => dx.max(dy),
+            Movement::Knight => dx
+                .div_ceil(2)
+                .max(dy.div_ceil(2))
+                .max((dx + dy).div_ceil(3)),
+        }
+    }
+}
+
+/// Risk paid by a move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cost {
+    /// The risk of the cell entered.
+    Enter,
+    /// The risk of the cell left.
+    Leave,
+}
+
+/// Lowest risk routes between any two cells of a cave.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Router {
+    pub movement: Movement,
+    pub cost: Cost,
+    /// Extra risk of a move not going in the direction of the previous one.
+    pub turn_penalty: u32,
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Router {
+            movement: Movement::Orthogonal,
+            cost: Cost::Enter,
+            turn_penalty: 0,
+        }
+    }
+}
+
+/// Position and, when turns cost something, index of the move that led there.
+type State = (Position, usize);
+
+/// How a state was reached: the index of the move and the direction of the previous state.
+type CameFrom = Option<(u8, u8)>;
+
+impl Router {
+    /// A* from `start` to `goal`, the heuristic being the fewest moves times the lowest risk.
+    /// Positions are queued with their f-score, outdated entries being skipped when popped.
+    /// Scores are only stored for the states reached, so huge caves cost what is explored.
+    pub fn find_route<C: Cave>(&self, cave: &C, start: Position, goal: Position) -> Option<Route> {
+        if !cave.contains(start) || !cave.contains(goal) {
+            return None;
+        }
+        let offsets = self.movement.offsets();
+        // Without turn penalty a single state per position is enough. Otherwise the last state
+        // of each position is the one reached by no move, used by the start only.
+        let directions = match self.turn_penalty {
+            0 => 1,
+            _ => offsets.len() + 1,
+        };
+        let start_state: State = (start, directions - 1);
+        let min_risk = cave.min_risk();
+        let heuristic = |position: Position| self.movement.min_moves(position, goal) * min_risk;
+        let index = |((x, y), direction): State| (y * cave.width() + x) * directions + direction;
+
+        // g-score and origin of each state reached, keyed by state index
+        let mut reached: HashMap<usize, (u32, CameFrom)> = HashMap::new();
+        reached.insert(index(start_state), (0, None));
+        let mut open_set: BinaryHeap<Reverse<(u32, State)>> = BinaryHeap::new();
+        open_set.push(Reverse((heuristic(start), start_state)));
+
+        while let Some(Reverse((f_score, current))) = open_set.pop() {
+            let (position, direction) = current;
+            let current_g_score = reached[&index(current)].0;
+            if position == goal {
+                let mut route: Route = vec![(position, current_g_score)];
+                let mut state = current;
+                while let (_, Some((i, direction))) = reached[&index(state)] {
+                    let (dx, dy) = offsets[i as usize];
+                    let ((x, y), _) = state;
+                    state = (
+                        (x.wrapping_add_signed(-dx), y.wrapping_add_signed(-dy)),
+                        direction as usize,
+                    );
+                    route.push((state.0, reached[&index(state)].0));
+                }
+                route.reverse();
+                return Some(route);
+            }
+            if f_score > current_g_score + heuristic(position) {
+                continue;
+            }
+            for (i, (dx, dy)) in offsets.iter().enumerate() {
+                let neighbor = match (
+                    position.0.checked_add_signed(*dx),
+                    position.1.checked_add_signed(*dy),
+                ) {
+                    (Some(x), Some(y)) if cave.contains((x, y)) => (x, y),
+                    _ => continue,
+                };
+                let next: State = (neighbor, if directions == 1 { 0 } else { i });
+                let mut risk = match self.cost {
+                    Cost::Enter => cave.risk(neighbor),
+                    Cost::Leave => cave.risk(position),
+                };
+                if directions > 1 && direction != i && direction != directions - 1 {
+                    risk += self.turn_penalty;
+                }
+                let tentative_g_score = current_g_score + risk;
+                let known_g_score = reached.get(&index(next)).map_or(u32::MAX, |(g, _)| *g);
+                if tentative_g_score < known_g_score {
+                    let origin = Some((i as u8, direction as u8));
+                    reached.insert(index(next), (tentative_g_score, origin));
+                    open_set.push(Reverse((tentative_g_score + heuristic(neighbor), next)));
+                }
+            }
+        }
+        return None;
+    }
+}
+
+fn total_risk<C: Cave>(cave: &C) -> Result<u32, String> {
+    let route = find_lowest_risk_path(cave).ok_or("No route to the bottom right")?;
+    return Ok(route.last().map_or(0, |(_, risk)| *risk));
+}
+
+/// Route of the puzzle, from the top left to the bottom right corner, entering a cell costing
+/// its risk.
+pub fn find_lowest_risk_path<C: Cave>(cave: &C) -> Option<Route> {
+    let goal = (cave.width().checked_sub(1)?, cave.height().checked_sub(1)?);
+    return Router::default().find_route(cave, (0, 0), goal);
+}
+
+/// The cave with the cells off `route` replaced by dots.
+pub fn render_route<C: Cave>(cave: &C, route: &Route) -> String {
+    let mut on_route: Grid<bool> = Grid::new(cave.width(), cave.height(), false);
+    for (position, _) in route {
+        on_route[*position] = true;
+    }
+    let cells = Grid::from_fn(cave.width(), cave.height(), |x, y| match on_route[(x, y)] {
+        true => char::from_digit(cave.risk((x, y)), 10).unwrap_or('?'),
+        false => '.',
+    });
+    return cells.render(|c| c.to_string());
+}
+
+/// Lowest risk route of `part` drawn over the cave, with its total risk.
+pub fn show_route(input: &Grid<u32>, part: u8) -> Result<String, String> {
+    let cave = TiledCave::new(input, if part == 2 { 5 } else { 1 });
+    let route = find_lowest_risk_path(&cave).ok_or("No route to the bottom right")?;
+    let risk = route.last().map_or(0, |(_, risk)| *risk);
+    return Ok(format!(
+        "{}\nTotal risk: {}",
+        render_route(&cave, &route),
+        risk
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Grid<u32> {
+        Grid::from_rows(vec![
+            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
+            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
+            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
+            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
+            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
+            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
+            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
+            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
+            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
+            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
+        ])
+        .unwrap()
+    }
+
+    fn sample_real_map() -> Grid<u32> {
+        Grid::from_rows(vec![
+            vec![
+                1, 1, 6, 3, 7, 5, 1, 7, 4, 2, 2, 2, 7, 4, 8, 6, 2, 8, 5, 3, 3, 3, 8, 5, 9, 7, 3, 9,
+                6, 4, 4, 4, 9, 6, 1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6,
+            ],
+            vec![
+                1, 3, 8, 1, 3, 7, 3, 6, 7, 2, 2, 4, 9, 2, 4, 8, 4, 7, 8, 3, 3, 5, 1, 3, 5, 9, 5, 8,
+                9, 4, 4, 6, 2, 4, 6, 1, 6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6,
+            ],
+            vec![
+                2, 1, 3, 6, 5, 1, 1, 3, 2, 8, 3, 2, 4, 7, 6, 2, 2, 4, 3, 9, 4, 3, 5, 8, 7, 3, 3, 5,
+                4, 1, 5, 4, 6, 9, 8, 4, 4, 6, 5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3,
+            ],
+            vec![
+                3, 6, 9, 4, 9, 3, 1, 5, 6, 9, 4, 7, 1, 5, 1, 4, 2, 6, 7, 1, 5, 8, 2, 6, 2, 5, 3, 7,
+                8, 2, 6, 9, 3, 7, 3, 6, 4, 8, 9, 3, 7, 1, 4, 8, 4, 7, 5, 9, 1, 4,
+            ],
+            vec![
+                7, 4, 6, 3, 4, 1, 7, 1, 1, 1, 8, 5, 7, 4, 5, 2, 8, 2, 2, 2, 9, 6, 8, 5, 6, 3, 9, 3,
+                3, 3, 1, 7, 9, 6, 7, 4, 1, 4, 4, 4, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5,
+            ],
+            vec![
+                1, 3, 1, 9, 1, 2, 8, 1, 3, 7, 2, 4, 2, 1, 2, 3, 9, 2, 4, 8, 3, 5, 3, 2, 3, 4, 1, 3,
+                5, 9, 4, 6, 4, 3, 4, 5, 2, 4, 6, 1, 5, 7, 5, 4, 5, 6, 3, 5, 7, 2,
+            ],
+            vec![
+                1, 3, 5, 9, 9, 1, 2, 4, 2, 1, 2, 4, 6, 1, 1, 2, 3, 5, 3, 2, 3, 5, 7, 2, 2, 3, 4, 6,
+                4, 3, 4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5,
+            ],
+            vec![
+                3, 1, 2, 5, 4, 2, 1, 6, 3, 9, 4, 2, 3, 6, 5, 3, 2, 7, 4, 1, 5, 3, 4, 7, 6, 4, 3, 8,
+                5, 2, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9, 8, 6, 5, 1, 7, 4,
+            ],
+            vec![
+                1, 2, 9, 3, 1, 3, 8, 5, 2, 1, 2, 3, 1, 4, 2, 4, 9, 6, 3, 2, 3, 4, 2, 5, 3, 5, 1, 7,
+                4, 3, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5,
+            ],
+            vec![
+                2, 3, 1, 1, 9, 4, 4, 5, 8, 1, 3, 4, 2, 2, 1, 5, 5, 6, 9, 2, 4, 5, 3, 3, 2, 6, 6, 7,
+                1, 3, 5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5,
+            ],
+            vec![
+                2, 2, 7, 4, 8, 6, 2, 8, 5, 3, 3, 3, 8, 5, 9, 7, 3, 9, 6, 4, 4, 4, 9, 6, 1, 8, 4, 1,
+                7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7,
+            ],
+            vec![
+                2, 4, 9, 2, 4, 8, 4, 7, 8, 3, 3, 5, 1, 3, 5, 9, 5, 8, 9, 4, 4, 6, 2, 4, 6, 1, 6, 9,
+                1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7,
+            ],
+            vec![
+                3, 2, 4, 7, 6, 2, 2, 4, 3, 9, 4, 3, 5, 8, 7, 3, 3, 5, 4, 1, 5, 4, 6, 9, 8, 4, 4, 6,
+                5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4,
+            ],
+            vec![
+                4, 7, 1, 5, 1, 4, 2, 6, 7, 1, 5, 8, 2, 6, 2, 5, 3, 7, 8, 2, 6, 9, 3, 7, 3, 6, 4, 8,
+                9, 3, 7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5,
+            ],
+            vec![
+                8, 5, 7, 4, 5, 2, 8, 2, 2, 2, 9, 6, 8, 5, 6, 3, 9, 3, 3, 3, 1, 7, 9, 6, 7, 4, 1, 4,
+                4, 4, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6,
+            ],
+            vec![
+                2, 4, 2, 1, 2, 3, 9, 2, 4, 8, 3, 5, 3, 2, 3, 4, 1, 3, 5, 9, 4, 6, 4, 3, 4, 5, 2, 4,
+                6, 1, 5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3,
+            ],
+            vec![
+                2, 4, 6, 1, 1, 2, 3, 5, 3, 2, 3, 5, 7, 2, 2, 3, 4, 6, 4, 3, 4, 6, 8, 3, 3, 4, 5, 7,
+                5, 4, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5, 6, 8, 1, 5, 5, 6, 7, 9, 7, 6,
+            ],
+            vec![
+                4, 2, 3, 6, 5, 3, 2, 7, 4, 1, 5, 3, 4, 7, 6, 4, 3, 8, 5, 2, 6, 4, 5, 8, 7, 5, 4, 9,
+                6, 3, 7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 8, 6, 7, 1, 9, 7, 6, 2, 8, 5,
+            ],
+            vec![
+                2, 3, 1, 4, 2, 4, 9, 6, 3, 2, 3, 4, 2, 5, 3, 5, 1, 7, 4, 3, 4, 5, 3, 6, 4, 6, 2, 8,
+                5, 4, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5, 6, 7, 5, 8, 6, 8, 4, 1, 7, 6,
+            ],
+            vec![
+                3, 4, 2, 2, 1, 5, 5, 6, 9, 2, 4, 5, 3, 3, 2, 6, 6, 7, 1, 3, 5, 6, 4, 4, 3, 7, 7, 8,
+                2, 4, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8, 6, 6, 5, 9, 9, 1, 4, 6,
+            ],
+            vec![
+                3, 3, 8, 5, 9, 7, 3, 9, 6, 4, 4, 4, 9, 6, 1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2,
+                8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7, 7, 7, 3, 9, 4, 2, 7, 4, 1, 8,
+            ],
+            vec![
+                3, 5, 1, 3, 5, 9, 5, 8, 9, 4, 4, 6, 2, 4, 6, 1, 6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1,
+                2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7, 7, 9, 5, 7, 9, 4, 9, 3, 4, 8,
+            ],
+            vec![
+                4, 3, 5, 8, 7, 3, 3, 5, 4, 1, 5, 4, 6, 9, 8, 4, 4, 6, 5, 2, 6, 5, 7, 1, 9, 5, 5, 7,
+                6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4, 8, 7, 9, 3, 2, 7, 7, 9, 8, 5,
+            ],
+            vec![
+                5, 8, 2, 6, 2, 5, 3, 7, 8, 2, 6, 9, 3, 7, 3, 6, 4, 8, 9, 3, 7, 1, 4, 8, 4, 7, 5, 9,
+                1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 9, 3, 6, 1, 6, 9, 7, 2, 3, 6,
+            ],
+            vec![
+                9, 6, 8, 5, 6, 3, 9, 3, 3, 3, 1, 7, 9, 6, 7, 4, 1, 4, 4, 4, 2, 8, 1, 7, 8, 5, 2, 5,
+                5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 4, 1, 3, 9, 1, 7, 4, 7, 7, 7,
+            ],
+            vec![
+                3, 5, 3, 2, 3, 4, 1, 3, 5, 9, 4, 6, 4, 3, 4, 5, 2, 4, 6, 1, 5, 7, 5, 4, 5, 6, 3, 5,
+                7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3, 7, 9, 7, 6, 7, 8, 5, 7, 9, 4,
+            ],
+            vec![
+                3, 5, 7, 2, 2, 3, 4, 6, 4, 3, 4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7, 9, 4, 4, 5, 6, 8,
+                6, 5, 6, 8, 1, 5, 5, 6, 7, 9, 7, 6, 7, 9, 2, 6, 6, 7, 8, 1, 8, 7,
+            ],
+            vec![
+                5, 3, 4, 7, 6, 4, 3, 8, 5, 2, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9, 8, 6, 5, 1,
+                7, 4, 8, 6, 7, 1, 9, 7, 6, 2, 8, 5, 9, 7, 8, 2, 1, 8, 7, 3, 9, 6,
+            ],
+            vec![
+                3, 4, 2, 5, 3, 5, 1, 7, 4, 3, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7, 3, 9,
+                6, 5, 6, 7, 5, 8, 6, 8, 4, 1, 7, 6, 7, 8, 6, 9, 7, 9, 5, 2, 8, 7,
+            ],
+            vec![
+                4, 5, 3, 3, 2, 6, 6, 7, 1, 3, 5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9,
+                3, 5, 7, 8, 6, 6, 5, 9, 9, 1, 4, 6, 8, 9, 7, 7, 6, 1, 1, 2, 5, 7,
+            ],
+            vec![
+                4, 4, 9, 6, 1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3,
+                9, 7, 7, 7, 3, 9, 4, 2, 7, 4, 1, 8, 8, 8, 4, 1, 5, 3, 8, 5, 2, 9,
+            ],
+            vec![
+                4, 6, 2, 4, 6, 1, 6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2,
+                3, 7, 7, 9, 5, 7, 9, 4, 9, 3, 4, 8, 8, 1, 6, 8, 1, 5, 1, 4, 5, 9,
+            ],
+            vec![
+                5, 4, 6, 9, 8, 4, 4, 6, 5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8,
+                7, 4, 8, 7, 9, 3, 2, 7, 7, 9, 8, 5, 9, 8, 1, 4, 3, 8, 8, 1, 9, 6,
+            ],
+            vec![
+                6, 9, 3, 7, 3, 6, 4, 8, 9, 3, 7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1,
+                2, 5, 9, 3, 6, 1, 6, 9, 7, 2, 3, 6, 1, 4, 7, 2, 7, 1, 8, 3, 4, 7,
+            ],
+            vec![
+                1, 7, 9, 6, 7, 4, 1, 4, 4, 4, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6,
+                6, 6, 4, 1, 3, 9, 1, 7, 4, 7, 7, 7, 5, 2, 4, 1, 2, 8, 5, 8, 8, 8,
+            ],
+            vec![
+                4, 6, 4, 3, 4, 5, 2, 4, 6, 1, 5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 6, 8, 6, 5, 6, 7, 4, 6,
+                8, 3, 7, 9, 7, 6, 7, 8, 5, 7, 9, 4, 8, 1, 8, 7, 8, 9, 6, 8, 1, 5,
+            ],
+            vec![
+                4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5, 6, 8, 1, 5, 5, 6, 7, 9,
+                7, 6, 7, 9, 2, 6, 6, 7, 8, 1, 8, 7, 8, 1, 3, 7, 7, 8, 9, 2, 9, 8,
+            ],
+            vec![
+                6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 8, 6, 7, 1, 9, 7, 6, 2,
+                8, 5, 9, 7, 8, 2, 1, 8, 7, 3, 9, 6, 1, 8, 9, 3, 2, 9, 8, 4, 1, 7,
+            ],
+            vec![
+                4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5, 6, 7, 5, 8, 6, 8, 4, 1,
+                7, 6, 7, 8, 6, 9, 7, 9, 5, 2, 8, 7, 8, 9, 7, 1, 8, 1, 6, 3, 9, 8,
+            ],
+            vec![
+                5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8, 6, 6, 5, 9, 9, 1,
+                4, 6, 8, 9, 7, 7, 6, 1, 1, 2, 5, 7, 9, 1, 8, 8, 7, 2, 2, 3, 6, 8,
+            ],
+            vec![
+                5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7, 7, 7, 3, 9, 4, 2, 7, 4,
+                1, 8, 8, 8, 4, 1, 5, 3, 8, 5, 2, 9, 9, 9, 5, 2, 6, 4, 9, 6, 3, 1,
+            ],
+            vec![
+                5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7, 7, 9, 5, 7, 9, 4, 9, 3,
+                4, 8, 8, 1, 6, 8, 1, 5, 1, 4, 5, 9, 9, 2, 7, 9, 2, 6, 2, 5, 6, 1,
+            ],
+            vec![
+                6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4, 8, 7, 9, 3, 2, 7, 7, 9,
+                8, 5, 9, 8, 1, 4, 3, 8, 8, 1, 9, 6, 1, 9, 2, 5, 4, 9, 9, 2, 1, 7,
+            ],
+            vec![
+                7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 9, 3, 6, 1, 6, 9, 7, 2,
+                3, 6, 1, 4, 7, 2, 7, 1, 8, 3, 4, 7, 2, 5, 8, 3, 8, 2, 9, 4, 5, 8,
+            ],
+            vec![
+                2, 8, 1, 7, 8, 5, 2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 4, 1, 3, 9, 1, 7, 4, 7,
+                7, 7, 5, 2, 4, 1, 2, 8, 5, 8, 8, 8, 6, 3, 5, 2, 3, 9, 6, 9, 9, 9,
+            ],
+            vec![
+                5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3, 7, 9, 7, 6, 7, 8, 5, 7,
+                9, 4, 8, 1, 8, 7, 8, 9, 6, 8, 1, 5, 9, 2, 9, 8, 9, 1, 7, 9, 2, 6,
+            ],
+            vec![
+                5, 7, 9, 4, 4, 5, 6, 8, 6, 5, 6, 8, 1, 5, 5, 6, 7, 9, 7, 6, 7, 9, 2, 6, 6, 7, 8, 1,
+                8, 7, 8, 1, 3, 7, 7, 8, 9, 2, 9, 8, 9, 2, 4, 8, 8, 9, 1, 3, 1, 9,
+            ],
+            vec![
+                7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 8, 6, 7, 1, 9, 7, 6, 2, 8, 5, 9, 7, 8, 2, 1, 8, 7, 3,
+                9, 6, 1, 8, 9, 3, 2, 9, 8, 4, 1, 7, 2, 9, 1, 4, 3, 1, 9, 5, 2, 8,
+            ],
+            vec![
+                5, 6, 4, 7, 5, 7, 3, 9, 6, 5, 6, 7, 5, 8, 6, 8, 4, 1, 7, 6, 7, 8, 6, 9, 7, 9, 5, 2,
+                8, 7, 8, 9, 7, 1, 8, 1, 6, 3, 9, 8, 9, 1, 8, 2, 9, 2, 7, 4, 1, 9,
+            ],
+            vec![
+                6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8, 6, 6, 5, 9, 9, 1, 4, 6, 8, 9, 7, 7, 6, 1, 1, 2,
+                5, 7, 9, 1, 8, 8, 7, 2, 2, 3, 6, 8, 1, 2, 9, 9, 8, 3, 3, 4, 7, 9,
+            ],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "1163751742
+        1381373672
+        2136511328
+        3694931569
+        7463417111
+        1319128137
+        1359912421
+        3125421639
+        1293138521
+        2311944581";
+        let expected = sample_input();
+        assert_eq!(Day15::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_show_sample_route() {
+        let expected = "1.........
+1.........
+2136511...
+......15..
+.......1..
+.......13.
+........2.
+........3.
+........21
+.........1
+Total risk: 40";
+        assert_eq!(show_route(&sample_input(), 1).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 40;
+        assert_eq!(Day15::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_find_paths_going_back() {
+        let input = Day15::parse_data(String::from("1111\n9991\n1111\n1999\n1111\n")).unwrap();
+        let route = find_lowest_risk_path(&input).unwrap();
+        assert_eq!(route.len(), 14);
+        assert_eq!(route[4], ((3, 1), 4));
+        assert_eq!(route[8], ((0, 2), 8));
+        assert_eq!(route[13], ((3, 4), 13));
+        let expected = "1111\n...1\n1111\n1...\n1111";
+        assert_eq!(render_route(&input, &route), expected);
+        assert_eq!(Day15::part1(&input).unwrap(), 13);
+    }
+
+    #[test]
+    fn it_should_follow_routing_rules() {
+        let cave = sample_input();
+        let risk = |router: Router, start: Position, goal: Position| {
+            let route = router.find_route(&cave, start, goal).unwrap();
+            assert_eq!(route.first().unwrap().0, start);
+            assert_eq!(route.last().unwrap().0, goal);
+            return route.last().unwrap().1;
+        };
+        let router = Router::default();
+        assert_eq!(risk(router, (9, 9), (0, 0)), 40);
+        let diagonal = Router {
+            movement: Movement::Diagonal,
+            ..router
+        };
+        assert_eq!(risk(diagonal, (0, 0), (9, 9)), 20);
+        let knight = Router {
+            movement: Movement::Knight,
+            ..router
+        };
+        assert_eq!(risk(knight, (0, 0), (9, 9)), 11);
+        let leave = Router {
+            cost: Cost::Leave,
+            ..router
+        };
+        assert_eq!(risk(leave, (0, 0), (2, 0)), 2);
+        assert_eq!(risk(router, (0, 0), (2, 0)), 7);
+        let turns = Router {
+            turn_penalty: 5,
+            ..router
+        };
+        assert_eq!(risk(turns, (0, 0), (9, 9)), 62);
+        let diagonal_turns = Router {
+            turn_penalty: 3,
+            ..diagonal
+        };
+        assert_eq!(risk(diagonal_turns, (9, 0), (0, 9)), 28);
+        assert_eq!(risk(router, (4, 4), (4, 4)), 0);
+    }
+
+    #[test]
+    fn it_should_report_missing_routes() {
+        let cave = Grid::new(2, 2, 1);
+        let knight = Router {
+            movement: Movement::Knight,
+            ..Router::default()
+        };
+        assert_eq!(knight.find_route(&cave, (0, 0), (1, 1)), None);
+        assert_eq!(Router::default().find_route(&cave, (0, 0), (2, 1)), None);
+    }
+
+    #[test]
+    fn it_should_solve_large_caves() {
+        let flat = Grid::new(500, 500, 1);
+        assert_eq!(Day15::part1(&flat).unwrap(), 998);
+        // every tile but the first one wraps the 9s around to 1..8
+        let nines = Grid::new(100, 100, 9);
+        assert_eq!(Day15::part2(&nines).unwrap(), 4590);
+    }
+
+    #[test]
+    fn it_should_compute_correctly_real_map() {
+        let input = sample_input();
+        let expected = sample_real_map();
+        let actual = TiledCave::new(&input, 5);
+        assert_eq!(actual.height(), expected.height());
+        assert_eq!(actual.width(), expected.width());
+        for ((x, y), risk) in expected.iter() {
+            assert_eq!(actual.risk((x, y)), *risk);
+        }
+        assert_eq!(actual.min_risk(), Cave::min_risk(&expected));
+    }
+
+    #[test]
+    fn it_should_route_through_large_tilings() {
+        let input = sample_input();
+        let tiled = TiledCave::new(&input, 25);
+        let materialized = input.tile(25, 25, |risk, tile_x, tile_y| {
+            (risk + (tile_x + tile_y) as u32 - 1) % 9 + 1
+        });
+        let risk = |route: Option<Route>| route.unwrap().last().unwrap().1;
+        assert_eq!(
+            risk(find_lowest_risk_path(&tiled)),
+            risk(find_lowest_risk_path(&materialized))
+        );
+        let turns = Router {
+            turn_penalty: 2,
+            ..Router::default()
+        };
+        let route = turns.find_route(&tiled, (30, 7), (0, 249)).unwrap();
+        for window in route.windows(2) {
+            let ((x1, y1), _) = window[0];
+            let ((x2, y2), _) = window[1];
+            assert_eq!(x1.abs_diff(x2) + y1.abs_diff(y2), 1);
+        }
+        assert_eq!(
+            risk(Some(route)),
+            risk(turns.find_route(&materialized, (30, 7), (0, 249)))
+        );
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 315;
+        assert_eq!(Day15::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,805 @@
+use aoc_common::{non_empty_lines, ParseError, Solution};
+use num_bigint::BigUint;
+use std::fmt;
+use std::ops::Range;
+
+/// Raw bits of a transmission, 4 for each hexadecimal digit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transmission {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Transmission;
+    type Part1Output = u64;
+    type Part2Output = BigUint;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut transmission = Transmission {
+            bytes: Vec::new(),
+            len: 0,
+        };
+        for line in non_empty_lines(&input) {
+            let hex = line.trimmed();
+            for (i, c) in hex.char_indices() {
+                let nibble = c
+                    .to_digit(16)
+                    .filter(|_| !c.is_ascii_lowercase())
+                    .ok_or_else(|| {
+                        line.unexpected(&hex[i..i + c.len_utf8()], "an uppercase hexadecimal digit")
+                    })?;
+                if transmission.len.is_multiple_of(8) {
+                    transmission.bytes.push((nibble as u8) << 4);
+                } else {
+                    *transmission.bytes.last_mut().unwrap() |= nibble as u8;
+                }
+                transmission.len += 4;
+            }
+        }
+        return Ok(transmission);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let packet = decode_packet(input)?;
+        return Ok(sum_version(&[packet]));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let packet = decode_packet(input)?;
+        return Ok(execute_operator(&packet));
+    }
+}
+
+fn sum_version(packets: &[Packet]) -> u64 {
+    packets
+        .iter()
+        .map(|packet| match packet {
+            Packet::LitteralValue(version, _, _) => *version,
+            Packet::Operator(version, _, _, _, sub_packets) => version + sum_version(sub_packets),
+        })
+        .sum()
+}
+
+type Version = u64;
+type Id = u64;
+type LitteralValue = BigUint;
+type LengthId = u64;
+type Length = u64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    LitteralValue(Version, Id, LitteralValue),
+    Operator(Version, Operator, LengthId, Length, Vec<Packet>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl Operator {
+    pub fn from_id(id: Id) -> Result<Self, String> {
+        match id {
+            0 => Ok(Operator::Sum),
+            1 => Ok(Operator::Product),
+            2 => Ok(Operator::Min),
+            3 => Ok(Operator::Max),
+            5 => Ok(Operator::Gt),
+            6 => Ok(Operator::Lt),
+            7 => Ok(Operator::Eq),
+            _ => Err(format!("Unknown operator type {}", id)),
+        }
+    }
+
+    pub fn id(&self) -> Id {
+        match self {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Min => 2,
+            Operator::Max => 3,
+            Operator::Gt => 5,
+            Operator::Lt => 6,
+            Operator::Eq => 7,
+        }
+    }
+
+    /// Comparisons take exactly two sub-packets, the other operators at least one.
+    pub fn check_arity(&self, count: usize) -> Result<(), String> {
+        match self {
+            Operator::Gt | Operator::Lt | Operator::Eq if count != 2 => Err(format!(
+                "{:?} should have 2 sub-packets, found {}",
+                self, count
+            )),
+            _ if count == 0 => Err(format!("{:?} should have sub-packets", self)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Binding strength in expressions, literals and function calls binding the most.
+    fn precedence(&self) -> u8 {
+        match self {
+            Operator::Gt | Operator::Lt | Operator::Eq => 1,
+            Operator::Sum => 2,
+            Operator::Product => 3,
+            Operator::Min | Operator::Max => 4,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Sum => "+",
+            Operator::Product => "*",
+            Operator::Min => "min",
+            Operator::Max => "max",
+            Operator::Gt => ">",
+            Operator::Lt => "<",
+            Operator::Eq => "==",
+        }
+    }
+}
+
+impl Packet {
+    pub fn version(&self) -> Version {
+        match self {
+            Packet::LitteralValue(version, ..) | Packet::Operator(version, ..) => *version,
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Packet::LitteralValue(..) => 4,
+            // A lone operand is printed without its operator.
+            Packet::Operator(_, Operator::Sum | Operator::Product, _, _, sub_packets)
+                if sub_packets.len() == 1 =>
+            {
+                sub_packets[0].precedence()
+            }
+            Packet::Operator(_, operator, ..) => operator.precedence(),
+        }
+    }
+
+    /// Writes the packet, in parentheses when it binds less than `precedence`.
+    fn write_expression(&self, f: &mut fmt::Formatter, precedence: u8) -> fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "(")?;
+            self.write_expression(f, 0)?;
+            return write!(f, ")");
+        }
+        match self {
+            Packet::LitteralValue(_, _, value) => write!(f, "{}", value),
+            Packet::Operator(_, operator @ (Operator::Min | Operator::Max), _, _, sub_packets) => {
+                write!(f, "{}(", operator.symbol())?;
+                for (i, sub_packet) in sub_packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    sub_packet.write_expression(f, 0)?;
+                }
+                write!(f, ")")
+            }
+            Packet::Operator(_, operator, _, _, sub_packets) => {
+                // Comparisons do not chain, their operands bind more.
+                let operand_precedence = match operator {
+                    Operator::Sum | Operator::Product => operator.precedence(),
+                    _ => operator.precedence() + 1,
+                };
+                for (i, sub_packet) in sub_packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " {} ", operator.symbol())?;
+                    }
+                    sub_packet.write_expression(f, operand_precedence)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Infix expression computed by the packet, such as `(1 + 3) * max(7, 8)`.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_expression(f, 0)
+    }
+}
+
+/// Reads fields of any width from the bits of a transmission.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    len: usize,
+    position: usize,
+    /// Bits of each packet read, in depth-first order.
+    spans: Vec<Range<usize>>,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(transmission: &'a Transmission) -> Self {
+        BitReader {
+            bytes: &transmission.bytes,
+            len: transmission.len,
+            position: 0,
+            spans: Vec::new(),
+        }
+    }
+
+    fn read_bit(&mut self) -> Result<bool, String> {
+        if self.position >= self.len {
+            return Err(format!(
+                "Transmission truncated at bit {}, {} bits long",
+                self.position, self.len
+            ));
+        }
+        let bit = self.bytes[self.position / 8] & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        return Ok(bit);
+    }
+
+    /// Reads a field of at most 64 bits, most significant bit first.
+    fn read(&mut self, width: usize) -> Result<u64, String> {
+        let mut value = 0;
+        for _ in 0..width {
+            value = (value << 1) | self.read_bit()? as u64;
+        }
+        return Ok(value);
+    }
+}
+
+/// Outermost packet of `transmission`, ignoring the padding after it.
+pub fn decode_packet(transmission: &Transmission) -> Result<Packet, String> {
+    return read_packet(&mut BitReader::new(transmission));
+}
+
+fn read_packet(reader: &mut BitReader) -> Result<Packet, String> {
+    let index = reader.spans.len();
+    reader.spans.push(reader.position..reader.position);
+    let packet = read_packet_fields(reader)?;
+    reader.spans[index].end = reader.position;
+    return Ok(packet);
+}
+
+fn read_packet_fields(reader: &mut BitReader) -> Result<Packet, String> {
+    let version = reader.read(3)?;
+    let id = reader.read(3)?;
+    if id == 4 {
+        let mut value = BigUint::default();
+        loop {
+            let has_more_groups = reader.read_bit()?;
+            value = (value << 4u8) + reader.read(4)?;
+            if !has_more_groups {
+                return Ok(Packet::LitteralValue(version, id, value));
+            }
+        }
+    }
+    let operator = Operator::from_id(id)?;
+    let length_id = reader.read(1)?;
+    let mut sub_packets: Vec<Packet> = Vec::new();
+    let length = if length_id == 0 {
+        let length = reader.read(15)?;
+        let end = reader.position + length as usize;
+        if end > reader.len {
+            return Err(format!(
+                "Transmission truncated: sub-packets at bit {} end at bit {}, after its {} bits",
+                reader.position, end, reader.len
+            ));
+        }
+        while reader.position < end {
+            sub_packets.push(read_packet(reader)?);
+        }
+        if reader.position != end {
+            return Err(format!(
+                "Sub-packets should end at bit {}, last one ends at bit {}",
+                end, reader.position
+            ));
+        }
+        length
+    } else {
+        let length = reader.read(11)?;
+        for _ in 0..length {
+            sub_packets.push(read_packet(reader)?);
+        }
+        length
+    };
+    operator.check_arity(sub_packets.len())?;
+    return Ok(Packet::Operator(
+        version,
+        operator,
+        length_id,
+        length,
+        sub_packets,
+    ));
+}
+
+/// Outermost packet of `transmission` and the bits of each of its packets, in depth-first order.
+fn decode_spans(transmission: &Transmission) -> Result<(Packet, Vec<Range<usize>>), String> {
+    let mut reader = BitReader::new(transmission);
+    let packet = read_packet(&mut reader)?;
+    return Ok((packet, reader.spans));
+}
+
+fn depth_first<'a>(packet: &'a Packet, depth: usize, packets: &mut Vec<(usize, &'a Packet)>) {
+    packets.push((depth, packet));
+    if let Packet::Operator(_, _, _, _, sub_packets) = packet {
+        for sub_packet in sub_packets {
+            depth_first(sub_packet, depth + 1, packets);
+        }
+    }
+}
+
+fn describe_packet(packet: &Packet) -> String {
+    match packet {
+        Packet::LitteralValue(version, id, value) => {
+            format!("version {} type {} literal {}", version, id, value)
+        }
+        Packet::Operator(version, operator, length_id, length, _) => format!(
+            "version {} type {} {:?} length type {} ({} {}) = {}",
+            version,
+            operator.id(),
+            operator,
+            length_id,
+            length,
+            if *length_id == 0 {
+                "bits"
+            } else {
+                "sub-packets"
+            },
+            execute_operator(packet)
+        ),
+    }
+}
+
+/// Indented tree of the packets of `transmission`, each with the bits it spans.
+pub fn inspect(transmission: &Transmission) -> Result<String, String> {
+    let (packet, spans) = decode_spans(transmission)?;
+    let mut packets = Vec::new();
+    depth_first(&packet, 0, &mut packets);
+    let lines: Vec<String> = packets
+        .into_iter()
+        .zip(spans)
+        .map(|((depth, packet), span)| {
+            format!(
+                "{}bits {}..{} {}",
+                "  ".repeat(depth),
+                span.start,
+                span.end,
+                describe_packet(packet)
+            )
+        })
+        .collect();
+    return Ok(lines.join("\n"));
+}
+
+/// Graphviz graph of the packets of `transmission`, each node pointing to its sub-packets.
+pub fn to_dot(transmission: &Transmission) -> Result<String, String> {
+    let (packet, spans) = decode_spans(transmission)?;
+    let mut packets = Vec::new();
+    depth_first(&packet, 0, &mut packets);
+    let mut lines = vec![String::from("digraph packets {")];
+    // Parents of each depth, the last one at a depth being the parent of the next packets.
+    let mut parents: Vec<usize> = Vec::new();
+    for (i, ((depth, packet), span)) in packets.iter().zip(spans).enumerate() {
+        let label = match packet {
+            Packet::LitteralValue(_, _, value) => value.to_string(),
+            Packet::Operator(_, operator, ..) => {
+                format!("{:?} = {}", operator, execute_operator(packet))
+            }
+        };
+        lines.push(format!(
+            "    p{} [label=\"{}\\nversion {}, bits {}..{}\"];",
+            i,
+            label,
+            packet.version(),
+            span.start,
+            span.end
+        ));
+        parents.truncate(*depth);
+        if let Some(parent) = parents.last() {
+            lines.push(format!("    p{} -> p{};", parent, i));
+        }
+        parents.push(i);
+    }
+    lines.push(String::from("}"));
+    return Ok(lines.join("\n"));
+}
+
+/// Bits written from the most significant, packed in bytes.
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        BitWriter {
+            bytes: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.bytes[index / 8] & (0x80 >> (index % 8)) != 0
+    }
+
+    fn push_bit(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Writes the `width` lowest bits of `value`, or fails when it does not fit.
+    fn write(&mut self, value: u64, width: usize, field: &str) -> Result<(), String> {
+        if width < 64 && value >> width != 0 {
+            return Err(format!(
+                "{} {} does not fit in {} bits",
+                field, value, width
+            ));
+        }
+        for i in (0..width).rev() {
+            self.push_bit((value >> i) & 1 == 1);
+        }
+        return Ok(());
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        for i in 0..other.len {
+            self.push_bit(other.bit(i));
+        }
+    }
+
+    /// Hexadecimal digits of the written bits, the last one padded with zeros.
+    fn to_hex(&self) -> String {
+        (0..self.len.div_ceil(4))
+            .map(|i| {
+                let nibble = (self.bytes[i / 2] >> (4 * (1 - i % 2))) & 0xF;
+                std::char::from_digit(nibble as u32, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+fn write_packet(writer: &mut BitWriter, packet: &Packet) -> Result<(), String> {
+    match packet {
+        Packet::LitteralValue(version, id, value) => {
+            if *id != 4 {
+                return Err(format!("A literal value should have type 4, found {}", id));
+            }
+            writer.write(*version, 3, "Version")?;
+            writer.write(*id, 3, "Type")?;
+            let digits = value.to_str_radix(16);
+            for (i, digit) in digits.chars().enumerate() {
+                writer.push_bit(i + 1 < digits.len());
+                writer.write(digit.to_digit(16).unwrap() as u64, 4, "Literal group")?;
+            }
+        }
+        Packet::Operator(version, operator, length_id, _, sub_packets) => {
+            operator.check_arity(sub_packets.len())?;
+            writer.write(*version, 3, "Version")?;
+            writer.write(operator.id(), 3, "Type")?;
+            let mut sub_writer = BitWriter::new();
+            for sub_packet in sub_packets {
+                write_packet(&mut sub_writer, sub_packet)?;
+            }
+            match length_id {
+                0 => {
+                    writer.push_bit(false);
+                    writer.write(sub_writer.len as u64, 15, "Sub-packets length")?;
+                }
+                1 => {
+                    writer.push_bit(true);
+                    writer.write(sub_packets.len() as u64, 11, "Sub-packets count")?;
+                }
+                _ => return Err(format!("Length type should be 0 or 1, found {}", length_id)),
+            }
+            writer.append(&sub_writer);
+        }
+    }
+    return Ok(());
+}
+
+/// Hexadecimal transmission of `packet`, using the length type stored in each operator.
+pub fn encode_packet(packet: &Packet) -> Result<String, String> {
+    let mut writer = BitWriter::new();
+    write_packet(&mut writer, packet)?;
+    return Ok(writer.to_hex());
+}
+
+/// Operator packet with the length of its sub-packets computed for `length_id`: 0 for a length in
+/// bits, 1 for a number of sub-packets.
+pub fn operator_packet(
+    version: Version,
+    operator: Operator,
+    length_id: LengthId,
+    sub_packets: Vec<Packet>,
+) -> Result<Packet, String> {
+    operator.check_arity(sub_packets.len())?;
+    let length = match length_id {
+        0 => {
+            let mut writer = BitWriter::new();
+            for sub_packet in &sub_packets {
+                write_packet(&mut writer, sub_packet)?;
+            }
+            writer.len as u64
+        }
+        _ => sub_packets.len() as u64,
+    };
+    return Ok(Packet::Operator(
+        version,
+        operator,
+        length_id,
+        length,
+        sub_packets,
+    ));
+}
+
+/// Value of a packet whose operators have the right number of sub-packets.
+fn execute_operator(packet: &Packet) -> BigUint {
+    match packet {
+        Packet::LitteralValue(_, _, value) => value.clone(),
+        Packet::Operator(_, operator, _, _, sub_packets) => {
+            let mut values = sub_packets.iter().map(execute_operator);
+            match operator {
+                Operator::Sum => values.sum(),
+                Operator::Product => values.product(),
+                Operator::Min => values.min().unwrap(),
+                Operator::Max => values.max().unwrap(),
+                Operator::Gt => BigUint::from(values.next() > values.next()),
+                Operator::Lt => BigUint::from(values.next() < values.next()),
+                Operator::Eq => BigUint::from(values.next() == values.next()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Transmission {
+        Transmission {
+            bytes: vec![0xD2, 0xFE, 0x28],
+            len: 24,
+        }
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "D2FE28\n";
+        let expected = sample_input();
+        assert_eq!(Day16::parse_data(input.to_string()).unwrap(), expected);
+        let input = "D2F\nE28\n";
+        assert_eq!(Day16::parse_data(input.to_string()).unwrap(), expected);
+        assert!(Day16::parse_data(String::from("d2fe28\n")).is_err());
+    }
+
+    #[test]
+    fn it_should_parse_packet_correctly() {
+        let expected = Packet::Operator(
+            4,
+            Operator::Min,
+            1,
+            1,
+            vec![Packet::Operator(
+                1,
+                Operator::Min,
+                1,
+                1,
+                vec![Packet::Operator(
+                    5,
+                    Operator::Min,
+                    0,
+                    11,
+                    vec![Packet::LitteralValue(6, 4, BigUint::from(15u8))],
+                )],
+            )],
+        );
+        assert_eq!(decode("8A004A801A8002F478"), expected);
+    }
+
+    fn literal(value: u64) -> Packet {
+        return Packet::LitteralValue(value % 8, 4, BigUint::from(value));
+    }
+
+    fn decode(hex: &str) -> Packet {
+        let input = Day16::parse_data(String::from(hex)).unwrap();
+        return decode_packet(&input).unwrap();
+    }
+
+    #[test]
+    fn it_should_decode_literals_wider_than_64_bits() {
+        let value = BigUint::parse_bytes(b"123456789ABCDEF0123456789ABCDEF", 16).unwrap();
+        let packet = Packet::LitteralValue(3, 4, value.clone());
+        let transmission = encode_packet(&packet).unwrap();
+        assert_eq!(decode(&transmission), packet);
+        let input = Day16::parse_data(transmission).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), value);
+    }
+
+    #[test]
+    fn it_should_report_truncated_transmissions() {
+        // Literal 2021 with its last group cut.
+        let input = Day16::parse_data(String::from("D2FE")).unwrap();
+        assert_eq!(
+            decode_packet(&input),
+            Err(String::from(
+                "Transmission truncated at bit 16, 16 bits long"
+            ))
+        );
+        // Sub-packets announced over 27 bits, only 16 left.
+        let input = Day16::parse_data(String::from("38006F4529")).unwrap();
+        assert!(Day16::part1(&input).is_err());
+        // Two sub-packets announced, only one sent.
+        let input = Day16::parse_data(String::from("EE0094")).unwrap();
+        assert!(Day16::part2(&input).is_err());
+        assert!(Day16::part1(&Day16::parse_data(String::new()).unwrap()).is_err());
+    }
+
+    #[test]
+    fn it_should_encode_packets() {
+        assert_eq!(
+            encode_packet(&Packet::LitteralValue(6, 4, BigUint::from(2021u16))).unwrap(),
+            "D2FE28"
+        );
+        for hex in ["38006F45291200", "EE00D40C823060", "8A004A801A8002F478"] {
+            let encoded = encode_packet(&decode(hex)).unwrap();
+            assert_eq!(encoded.trim_end_matches('0'), hex.trim_end_matches('0'));
+        }
+        assert!(encode_packet(&Packet::LitteralValue(8, 4, BigUint::from(1u8))).is_err());
+        assert!(encode_packet(&Packet::Operator(1, Operator::Lt, 0, 0, vec![])).is_err());
+    }
+
+    #[test]
+    fn it_should_round_trip_crafted_packets() {
+        for length_id in [0, 1] {
+            let sub_packets = (0..20).map(|i| literal(i * 7919)).collect();
+            let inner = operator_packet(2, Operator::Sum, 1 - length_id, sub_packets).unwrap();
+            let outer =
+                operator_packet(5, Operator::Max, length_id, vec![inner, literal(u64::MAX)])
+                    .unwrap();
+            assert_eq!(decode(&encode_packet(&outer).unwrap()), outer);
+        }
+        let oversized = operator_packet(0, Operator::Sum, 1, vec![literal(1); 2048]).unwrap();
+        assert!(encode_packet(&oversized).is_err());
+    }
+
+    #[test]
+    fn it_should_check_operator_arity() {
+        // Greater than with a single sub-packet.
+        let input = Day16::parse_data(String::from("16004408")).unwrap();
+        assert_eq!(
+            decode_packet(&input),
+            Err(String::from("Gt should have 2 sub-packets, found 1"))
+        );
+        assert!(operator_packet(0, Operator::Eq, 1, vec![literal(1); 3]).is_err());
+        assert!(operator_packet(0, Operator::Max, 0, vec![]).is_err());
+        assert_eq!(
+            Operator::from_id(4),
+            Err(String::from("Unknown operator type 4"))
+        );
+    }
+
+    #[test]
+    fn it_should_print_expressions() {
+        let sum = operator_packet(0, Operator::Sum, 1, vec![literal(1), literal(3)]).unwrap();
+        let max = operator_packet(0, Operator::Max, 1, vec![literal(7), literal(8)]).unwrap();
+        let product = operator_packet(0, Operator::Product, 0, vec![sum, max]).unwrap();
+        assert_eq!(product.to_string(), "(1 + 3) * max(7, 8)");
+        assert_eq!(decode("C200B40A82").to_string(), "1 + 2");
+        assert_eq!(decode("880086C3E88112").to_string(), "min(7, 8, 9)");
+        assert_eq!(
+            decode("8A004A801A8002F478").to_string(),
+            "min(min(min(15)))"
+        );
+        assert_eq!(
+            decode("9C0141080250320F1802104A08").to_string(),
+            "1 + 3 == 2 * 2"
+        );
+        let less = operator_packet(0, Operator::Lt, 1, vec![literal(5), literal(6)]).unwrap();
+        let equal = operator_packet(0, Operator::Eq, 1, vec![less, literal(1)]).unwrap();
+        assert_eq!(equal.to_string(), "(5 < 6) == 1");
+    }
+
+    #[test]
+    fn it_should_inspect_packets() {
+        let input = Day16::parse_data(String::from("38006F45291200")).unwrap();
+        let expected = "bits 0..49 version 1 type 6 Lt length type 0 (27 bits) = 1
+  bits 22..33 version 6 type 4 literal 10
+  bits 33..49 version 2 type 4 literal 20";
+        assert_eq!(inspect(&input).unwrap(), expected);
+        let expected = r#"digraph packets {
+    p0 [label="Lt = 1\nversion 1, bits 0..49"];
+    p1 [label="10\nversion 6, bits 22..33"];
+    p0 -> p1;
+    p2 [label="20\nversion 2, bits 33..49"];
+    p0 -> p2;
+}"#;
+        assert_eq!(to_dot(&input).unwrap(), expected);
+        let input = Day16::parse_data(String::from("38006F4529")).unwrap();
+        assert!(inspect(&input).is_err());
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_1() {
+        let input = Day16::parse_data(String::from("8A004A801A8002F478")).unwrap();
+        let expected = 16;
+        assert_eq!(Day16::part1(&input).unwrap(), expected);
+    }
+    #[test]
+    fn it_should_compute_part1_correctly_2() {
+        let input = Day16::parse_data(String::from("620080001611562C8802118E34")).unwrap();
+        let expected = 12;
+        assert_eq!(Day16::part1(&input).unwrap(), expected);
+    }
+    #[test]
+    fn it_should_compute_part1_correctly_3() {
+        let input = Day16::parse_data(String::from("C0015000016115A2E0802F182340")).unwrap();
+        let expected = 23;
+        assert_eq!(Day16::part1(&input).unwrap(), expected);
+    }
+    #[test]
+    fn it_should_compute_part1_correctly_4() {
+        let input = Day16::parse_data(String::from("A0016C880162017C3686B18A3D4780")).unwrap();
+        let expected = 31;
+        assert_eq!(Day16::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_1() {
+        let input = Day16::parse_data(String::from("C200B40A82")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(3u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_2() {
+        let input = Day16::parse_data(String::from("04005AC33890")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(54u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_3() {
+        let input = Day16::parse_data(String::from("880086C3E88112")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(7u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_4() {
+        let input = Day16::parse_data(String::from("CE00C43D881120")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(9u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_5() {
+        let input = Day16::parse_data(String::from("D8005AC2A8F0")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(1u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_6() {
+        let input = Day16::parse_data(String::from("F600BC2D8F")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(0u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_7() {
+        let input = Day16::parse_data(String::from("9C005AC2F8F0")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(0u8));
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly_8() {
+        let input = Day16::parse_data(String::from("9C0141080250320F1802104A08")).unwrap();
+        assert_eq!(Day16::part2(&input).unwrap(), BigUint::from(1u8));
+    }
+}
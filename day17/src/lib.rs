@@ -0,0 +1,240 @@
+use aoc_common::{missing_at_end, non_empty_lines, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Target {
+    from_x: i64,
+    end_x: i64,
+    from_y: i64,
+    end_y: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PositionStatus {
+    Before,
+    OnTarget,
+    After,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Target;
+    type Part1Output = i64;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let re =
+            Regex::new(r"target area: x=([-0-9]+)..([-0-9]+), y=([-0-9]+)..([-0-9]+)").unwrap();
+        let line = non_empty_lines(&input)
+            .next()
+            .ok_or_else(|| missing_at_end(&input, "a target area"))?;
+        let captured = re
+            .captures(line.text)
+            .ok_or_else(|| line.unexpected(line.trimmed(), "target area: x=A..B, y=C..D"))?;
+
+        let from_x: i64 = line.parse_number(&captured[1])?;
+        let end_x: i64 = line.parse_number(&captured[2])?;
+        let from_y: i64 = line.parse_number(&captured[3])?;
+        let end_y: i64 = line.parse_number(&captured[4])?;
+        // probes are only launched right and fall down, the target has to be that way
+        if from_x <= 0 {
+            return Err(line.unexpected(&captured[1], "a positive x"));
+        }
+        if end_x < from_x {
+            let expected = format!("an x of at least {}", from_x);
+            return Err(line.unexpected(&captured[2], &expected));
+        }
+        if end_y < from_y {
+            let expected = format!("a y of at least {}", from_y);
+            return Err(line.unexpected(&captured[4], &expected));
+        }
+        if end_y >= 0 {
+            return Err(line.unexpected(&captured[4], "a negative y"));
+        }
+        return Ok(Target {
+            from_x,
+            end_x,
+            from_y,
+            end_y,
+        });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let reached_target = get_parameter_to_reach_target(input);
+
+        return Ok(reached_target
+            .iter()
+            .map(|(_, max_y)| *max_y)
+            .max()
+            .ok_or("No launch velocity reaches the target")?);
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let reached_target = get_parameter_to_reach_target(input);
+        return Ok(reached_target.len());
+    }
+}
+
+fn get_position_status(target: Target, position: (i64, i64)) -> PositionStatus {
+    if position.0 < target.from_x {
+        if position.1 < target.from_y {
+            return PositionStatus::After;
+        } else {
+            return PositionStatus::Before;
+        }
+    } else if target.end_x < position.0 {
+        return PositionStatus::After;
+    } else {
+        if position.1 < target.from_y {
+            return PositionStatus::After;
+        } else if target.end_y < position.1 {
+            return PositionStatus::Before;
+        } else {
+            return PositionStatus::OnTarget;
+        }
+    }
+}
+
+fn get_parameter_to_reach_target(input: &Target) -> Vec<((i64, i64), i64)> {
+    let start_position: (i64, i64) = (0, 0);
+    let mut reached_target: Vec<((i64, i64), i64)> = Vec::new();
+    // these two for loops are using pretty much magic random numbers...
+    for start_x in (-input.end_x * 2)..(input.end_x * 2) {
+        for start_y in (-input.from_y.abs() * 2)..(input.from_y.abs() * 2) {
+            let mut x = start_x;
+            let mut y = start_y;
+            let mut max_y = 0;
+            let mut current_position = start_position;
+            loop {
+                if max_y < current_position.1 {
+                    max_y = current_position.1;
+                }
+                let status = get_position_status(*input, current_position);
+                match status {
+                    PositionStatus::Before => {
+                        current_position = (current_position.0 + x, current_position.1 + y);
+                        if x < 0 {
+                            x += 1;
+                        } else if x > 0 {
+                            x -= 1;
+                        }
+                        y -= 1;
+                    }
+                    PositionStatus::OnTarget => {
+                        reached_target.push((current_position, max_y));
+                        break;
+                    }
+                    PositionStatus::After => break,
+                }
+            }
+        }
+    }
+    return reached_target;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Target {
+        Target {
+            from_x: 20,
+            end_x: 30,
+            from_y: -10,
+            end_y: -5,
+        }
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "target area: x=20..30, y=-10..-5\n";
+        let expected = sample_input();
+        assert_eq!(Day17::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_reject_unreachable_target() {
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 31,
+            text: String::from("5"),
+            expected: String::from("a negative y"),
+        };
+        let input = "target area: x=20..30, y=-10..5\n";
+        assert_eq!(Day17::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 20,
+            text: String::from("10"),
+            expected: String::from("an x of at least 20"),
+        };
+        let input = "target area: x=20..10, y=-10..-5\n";
+        assert_eq!(Day17::parse_data(input.to_string()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_detect_as_before_position_with_x_in_target_but_y_over() {
+        let target = sample_input();
+        let position: (i64, i64) = (25, 2);
+        assert_eq!(
+            get_position_status(target, position),
+            PositionStatus::Before
+        );
+    }
+
+    #[test]
+    fn it_should_detect_as_before_position_with_y_in_target_but_x_before() {
+        let target = sample_input();
+        let position: (i64, i64) = (18, -8);
+        assert_eq!(
+            get_position_status(target, position),
+            PositionStatus::Before
+        );
+    }
+
+    #[test]
+    fn it_should_detect_as_ontarget_position_with_x_and_y_on_target() {
+        let target = sample_input();
+        let position: (i64, i64) = (25, -8);
+        assert_eq!(
+            get_position_status(target, position),
+            PositionStatus::OnTarget
+        );
+    }
+
+    #[test]
+    fn it_should_detect_as_after_position_with_x_after_target() {
+        let target = sample_input();
+        let position: (i64, i64) = (31, -8);
+        assert_eq!(get_position_status(target, position), PositionStatus::After);
+    }
+
+    #[test]
+    fn it_should_detect_as_after_position_with_y_bellow_target() {
+        let target = sample_input();
+        let position: (i64, i64) = (25, -11);
+        assert_eq!(get_position_status(target, position), PositionStatus::After);
+    }
+
+    #[test]
+    fn it_should_detect_as_after_position_with_y_bellow_target_and_x_before() {
+        let target = sample_input();
+        let position: (i64, i64) = (10, -11);
+        assert_eq!(get_position_status(target, position), PositionStatus::After);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 45;
+        assert_eq!(Day17::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 112;
+        assert_eq!(Day17::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,549 @@
+use aoc_common::{non_empty_lines, InputLine, ParseError, Solution};
+
+type NodeId = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SnailfishNumber {
+    Number(u32),
+    Pair(NodeId, NodeId),
+}
+
+impl SnailfishNumber {
+    fn get_num(self) -> Option<u32> {
+        match self {
+            SnailfishNumber::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+/// Arena of the nodes of a snailfish number. A `NodeId` stays valid until its node is removed
+/// or the arena is compacted, and removed slots are reused by the next added nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnailfishTree {
+    nodes: Vec<Option<SnailfishNumber>>,
+    free: Vec<NodeId>,
+    root: NodeId,
+}
+
+impl SnailfishTree {
+    fn new() -> Self {
+        SnailfishTree {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: 0,
+        }
+    }
+    fn node(&self, node_id: NodeId) -> SnailfishNumber {
+        self.nodes[node_id].expect("removed snailfish node")
+    }
+    fn add_number(&mut self, n: SnailfishNumber) -> NodeId {
+        match self.free.pop() {
+            Some(node_id) => {
+                self.nodes[node_id] = Some(n);
+                node_id
+            }
+            None => {
+                self.nodes.push(Some(n));
+                self.nodes.len() - 1
+            }
+        }
+    }
+    fn remove_number(&mut self, node_id: NodeId) {
+        self.nodes[node_id] = None;
+        self.free.push(node_id);
+    }
+    fn replace(&mut self, node_id: NodeId, n: SnailfishNumber) {
+        self.nodes[node_id] = Some(n);
+    }
+    /// Copies the subtree of `other` under `node_id` in this arena, returning the id of its copy.
+    fn copy_subtree(&mut self, other: &SnailfishTree, node_id: NodeId) -> NodeId {
+        match other.node(node_id) {
+            SnailfishNumber::Pair(left_id, right_id) => {
+                let left_copy = self.copy_subtree(other, left_id);
+                let right_copy = self.copy_subtree(other, right_id);
+                self.add_number(SnailfishNumber::Pair(left_copy, right_copy))
+            }
+            number => self.add_number(number),
+        }
+    }
+    /// Drops removed slots, renumbering every node. Ids obtained before are invalidated.
+    pub fn compact(&mut self) {
+        let mut compacted = SnailfishTree::new();
+        compacted.root = compacted.copy_subtree(self, self.root);
+        *self = compacted;
+    }
+    /// Regular numbers from left to right.
+    fn get_numbers(&self) -> Vec<NodeId> {
+        fn rec(t: &SnailfishTree, node_id: NodeId, numbers: &mut Vec<NodeId>) {
+            match t.node(node_id) {
+                SnailfishNumber::Number(_) => numbers.push(node_id),
+                SnailfishNumber::Pair(left_id, right_id) => {
+                    rec(t, left_id, numbers);
+                    rec(t, right_id, numbers);
+                }
+            }
+        }
+        let mut numbers = Vec::new();
+        rec(self, self.root, &mut numbers);
+        return numbers;
+    }
+    /// Leftmost pair of two regular numbers nested inside four pairs or more.
+    fn find_exploding_pair(&self) -> Option<NodeId> {
+        fn rec(t: &SnailfishTree, node_id: NodeId, depth: usize) -> Option<NodeId> {
+            match t.node(node_id) {
+                SnailfishNumber::Number(_) => None,
+                SnailfishNumber::Pair(left_id, right_id)
+                    if depth >= 4
+                        && t.node(left_id).get_num().is_some()
+                        && t.node(right_id).get_num().is_some() =>
+                {
+                    Some(node_id)
+                }
+                SnailfishNumber::Pair(left_id, right_id) => {
+                    rec(t, left_id, depth + 1).or_else(|| rec(t, right_id, depth + 1))
+                }
+            }
+        }
+        return rec(self, self.root, 0);
+    }
+    /// Leftmost regular number greater than 9.
+    fn find_splitting_number(&self) -> Option<NodeId> {
+        self.get_numbers()
+            .into_iter()
+            .find(|node_id| self.node(*node_id).get_num().unwrap() > 9)
+    }
+    fn explode(&mut self, node_id: NodeId) {
+        let SnailfishNumber::Pair(left_id, right_id) = self.node(node_id) else {
+            return;
+        };
+        let numbers = self.get_numbers();
+        let left_index = numbers.iter().position(|id| *id == left_id).unwrap();
+        let right_index = numbers.iter().position(|id| *id == right_id).unwrap();
+        if let Some(&first_left_number) = left_index.checked_sub(1).and_then(|i| numbers.get(i)) {
+            let value = self.node(first_left_number).get_num().unwrap()
+                + self.node(left_id).get_num().unwrap();
+            self.replace(first_left_number, SnailfishNumber::Number(value));
+        }
+        if let Some(&first_right_number) = numbers.get(right_index + 1) {
+            let value = self.node(first_right_number).get_num().unwrap()
+                + self.node(right_id).get_num().unwrap();
+            self.replace(first_right_number, SnailfishNumber::Number(value));
+        }
+        self.replace(node_id, SnailfishNumber::Number(0));
+        self.remove_number(left_id);
+        self.remove_number(right_id);
+    }
+    fn split(&mut self, node_id: NodeId) {
+        let current_number = self.node(node_id).get_num().unwrap();
+        let left_number_id = self.add_number(SnailfishNumber::Number(current_number / 2));
+        let right_number_id =
+            self.add_number(SnailfishNumber::Number(current_number - current_number / 2));
+        self.replace(
+            node_id,
+            SnailfishNumber::Pair(left_number_id, right_number_id),
+        );
+    }
+    /// Applies one explode, or one split when nothing explodes. Returns false when already reduced.
+    fn reduce_once(&mut self) -> bool {
+        if let Some(node_id) = self.find_exploding_pair() {
+            self.explode(node_id);
+            return true;
+        }
+        if let Some(node_id) = self.find_splitting_number() {
+            self.split(node_id);
+            return true;
+        }
+        return false;
+    }
+    fn reduce(&mut self) {
+        while self.reduce_once() {}
+    }
+    fn add(&self, other: &Self) -> Self {
+        let mut res = SnailfishTree::new();
+        let left_id = res.copy_subtree(self, self.root);
+        let right_id = res.copy_subtree(other, other.root);
+        res.root = res.add_number(SnailfishNumber::Pair(left_id, right_id));
+        return res;
+    }
+    fn add_and_reduce(&self, other: &Self) -> Self {
+        let mut res = self.add(other);
+        res.reduce();
+        res.compact();
+        return res;
+    }
+}
+
+impl SnailfishTree {
+    fn parse_line(line: &InputLine) -> Result<Self, ParseError> {
+        let text = line.trimmed();
+        let mut res = SnailfishTree::new();
+        let mut stack: Vec<NodeId> = Vec::new();
+        let mut depth = 0;
+        let mut it = text.char_indices().peekable();
+        while let Some((i, current)) = it.next() {
+            match current {
+                '[' => {
+                    depth += 1;
+                }
+                ']' => {
+                    if depth == 0 || stack.len() < 2 {
+                        return Err(line.unexpected(&text[i..i + 1], "a number or a pair"));
+                    }
+                    depth -= 1;
+                    let right_id = stack.pop().unwrap();
+                    let left_id = stack.pop().unwrap();
+
+                    let n = SnailfishNumber::Pair(left_id, right_id);
+
+                    let node_id = res.add_number(n);
+                    stack.push(node_id);
+                }
+                ',' => {}
+                '0'..='9' => {
+                    let mut end = i + 1;
+                    while let Some((j, _)) = it.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = j + 1;
+                    }
+                    let n = SnailfishNumber::Number(line.parse_number(&text[i..end])?);
+                    let node_id = res.add_number(n);
+                    stack.push(node_id);
+                }
+                _ => {
+                    let token = &text[i..i + current.len_utf8()];
+                    return Err(line.unexpected(token, "a snailfish number"));
+                }
+            }
+        }
+        if depth != 0 || stack.len() != 1 {
+            return Err(line.missing("]"));
+        }
+        res.root = stack[0];
+
+        return Ok(res);
+    }
+}
+
+impl std::str::FromStr for SnailfishTree {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SnailfishTree::parse_line(&InputLine { number: 1, text: s })
+    }
+}
+
+/// Canonical notation, such as `[[1,2],3]`, which parses back to the same number.
+impl std::fmt::Display for SnailfishTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn rec(
+            t: &SnailfishTree,
+            node_id: NodeId,
+            f: &mut std::fmt::Formatter<'_>,
+        ) -> std::fmt::Result {
+            match t.node(node_id) {
+                SnailfishNumber::Number(n) => write!(f, "{}", n),
+                SnailfishNumber::Pair(left_id, right_id) => {
+                    write!(f, "[")?;
+                    rec(t, left_id, f)?;
+                    write!(f, ",")?;
+                    rec(t, right_id, f)?;
+                    write!(f, "]")
+                }
+            }
+        }
+        rec(self, self.root, f)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailfishTree>;
+    type Part1Output = u32;
+    type Part2Output = u32;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input)
+            .map(|line| SnailfishTree::parse_line(&line))
+            .collect();
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let (first, others) = input.split_first().ok_or("No snailfish number to add")?;
+        let sum = others
+            .iter()
+            .fold(first.clone(), |acc, cur| acc.add_and_reduce(cur));
+        return Ok(compute_magnitude(&sum));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let mut largest: Option<u32> = None;
+        for (i, left) in input.iter().enumerate() {
+            for (j, right) in input.iter().enumerate() {
+                if i != j {
+                    let magnitude = compute_magnitude(&left.add_and_reduce(right));
+                    largest = Some(largest.map_or(magnitude, |l| l.max(magnitude)));
+                }
+            }
+        }
+        return largest.ok_or(String::from("At least two snailfish numbers are needed"));
+    }
+}
+
+fn compute_magnitude(tree: &SnailfishTree) -> u32 {
+    fn rec(t: &SnailfishTree, node_id: NodeId) -> u32 {
+        let node = t.node(node_id);
+        match node {
+            SnailfishNumber::Number(n) => n,
+            SnailfishNumber::Pair(l, r) => 3 * rec(t, l) + 2 * rec(t, r),
+        }
+    }
+
+    return rec(tree, tree.root);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Vec<SnailfishTree> {
+        Day18::parse_data(
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+        [[[5,[2,8]],4],[5,[[9,9],0]]]
+        [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+        [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+        [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+        [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+        [[[[5,4],[7,7]],8],[[8,3],8]]
+        [[9,3],[[9,9],[6,[4,9]]]]
+        [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+        [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
+                .to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_report_malformed_number() {
+        let expected = ParseError::UnexpectedToken {
+            line: 2,
+            column: 13,
+            text: String::from("x"),
+            expected: String::from("a snailfish number"),
+        };
+        let input = "[1,2]\n        [[3,x],4]";
+        assert_eq!(Day18::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::MissingToken {
+            line: 1,
+            column: 7,
+            expected: String::from("]"),
+        };
+        assert_eq!("[[1,2]".parse::<SnailfishTree>(), Err(expected));
+    }
+
+    #[test]
+    fn it_should_parse_correctly_1() {
+        let input = "[1,2]";
+        let expected = SnailfishTree {
+            nodes: vec![
+                Some(SnailfishNumber::Number(1)),
+                Some(SnailfishNumber::Number(2)),
+                Some(SnailfishNumber::Pair(0, 1)),
+            ],
+            free: vec![],
+            root: 2,
+        };
+        assert_eq!(input.parse::<SnailfishTree>().unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_parse_correctly_2() {
+        let input = "[[1,2],3]";
+        let expected = SnailfishTree {
+            nodes: vec![
+                Some(SnailfishNumber::Number(1)),
+                Some(SnailfishNumber::Number(2)),
+                Some(SnailfishNumber::Pair(0, 1)),
+                Some(SnailfishNumber::Number(3)),
+                Some(SnailfishNumber::Pair(2, 3)),
+            ],
+            free: vec![],
+            root: 4,
+        };
+        assert_eq!(input.parse::<SnailfishTree>().unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_magnitude_1() {
+        let input = "[[1,2],[[3,4],5]]".parse::<SnailfishTree>().unwrap();
+        assert_eq!(compute_magnitude(&input), 143);
+    }
+    #[test]
+    fn it_should_compute_magnitude_2() {
+        let input = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 1384);
+    }
+    #[test]
+    fn it_should_compute_magnitude_3() {
+        let input = "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 445);
+    }
+    #[test]
+    fn it_should_compute_magnitude_4() {
+        let input = "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 791);
+    }
+    #[test]
+    fn it_should_compute_magnitude_5() {
+        let input = "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 1137);
+    }
+    #[test]
+    fn it_should_compute_magnitude_6() {
+        let input = "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 3488);
+    }
+    #[test]
+    fn it_should_compute_magnitude_example() {
+        let input = "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        assert_eq!(compute_magnitude(&input), 4140);
+    }
+
+    #[test]
+    fn it_should_print_parsed_numbers() {
+        for input in [
+            "[1,2]",
+            "[[1,2],3]",
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+        ] {
+            assert_eq!(input.parse::<SnailfishTree>().unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn it_should_add_and_reduce() {
+        let left = "[[[[4,3],4],4],[7,[[8,4],9]]]"
+            .parse::<SnailfishTree>()
+            .unwrap();
+        let right = "[1,1]".parse::<SnailfishTree>().unwrap();
+        let mut sum = left.add(&right);
+        assert_eq!(sum.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        let mut steps = Vec::new();
+        while sum.reduce_once() {
+            steps.push(sum.to_string());
+        }
+        let expected = vec![
+            "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+            "[[[[0,7],4],[15,[0,13]]],[1,1]]",
+            "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+            "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+        ];
+        assert_eq!(steps, expected);
+        assert_eq!(
+            left.add_and_reduce(&right).to_string(),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+    }
+
+    #[test]
+    fn it_should_explode_leftmost_pair_first() {
+        for (input, expected) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            ("[[[[[[1,2],3],4],5],6],7]", "[[[[[0,5],4],5],6],7]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+        ] {
+            let mut number = input.parse::<SnailfishTree>().unwrap();
+            number.reduce_once();
+            assert_eq!(number.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn it_should_reuse_and_compact_arena() {
+        let mut number = "[[[[[9,8],1],2],3],4]".parse::<SnailfishTree>().unwrap();
+        let arena_size = number.nodes.len();
+        let exploding_pair = number.find_exploding_pair().unwrap();
+        let untouched_number = number.get_numbers()[5];
+        number.reduce_once();
+        assert_eq!(number.free.len(), 2);
+        assert_eq!(number.node(untouched_number), SnailfishNumber::Number(4));
+        number.split(exploding_pair);
+        assert_eq!(number.free.len(), 0);
+        assert_eq!(number.nodes.len(), arena_size);
+
+        number.reduce_once();
+        number.compact();
+        assert_eq!(number.to_string(), "[[[[0,9],2],3],4]");
+        assert_eq!(number.nodes.len(), 9);
+        assert!(number.free.is_empty());
+    }
+
+    #[test]
+    fn it_should_keep_arena_size_bounded_when_summing() {
+        let input = sample_input();
+        let mut sum = input[0].clone();
+        for _ in 0..20 {
+            for number in &input {
+                sum = sum.add_and_reduce(number);
+                assert_eq!(sum.nodes.len(), 2 * sum.get_numbers().len() - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_sum_a_list() {
+        let input = Day18::parse_data(
+            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+        [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+        [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+        [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+        [7,[5,[[3,8],[1,4]]]]
+        [[2,[2,2]],[8,[8,1]]]
+        [2,9]
+        [1,[[[9,3],9],[[9,0],[0,7]]]]
+        [[[5,[7,4]],7],1]
+        [[[[4,2],2],6],[8,7]]"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(Day18::part1(&input).unwrap(), 3488);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 4140;
+        assert_eq!(Day18::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_sum_deeply_nested_numbers() {
+        let input = Day18::parse_data("[[[[[[1,2],3],4],5],6],7]\n[1,1]".to_string()).unwrap();
+        assert_eq!(Day18::part1(&input).unwrap(), 1195);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 3993;
+        assert_eq!(Day18::part2(&input).unwrap(), expected);
+    }
+}
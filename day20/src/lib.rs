@@ -0,0 +1,182 @@
+use aoc_common::{missing_at_end, non_empty_lines, Grid, ParseError, Solution};
+#[derive(Clone, Copy, PartialEq)]
+enum Pixel {
+    Light,
+    Dark,
+}
+
+impl Pixel {
+    const fn is_light(&self) -> bool {
+        matches!(*self, Pixel::Light)
+    }
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Pixel::Light),
+            '.' => Some(Pixel::Dark),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Debug for Pixel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Pixel::Light => write!(f, "█"),
+            Pixel::Dark => write!(f, "░"),
+        }
+    }
+}
+
+type Algorithm = Vec<Pixel>;
+type Image = Grid<Pixel>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    algorithm: Algorithm,
+    image: Image,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let mut lines = non_empty_lines(&input);
+
+        let algorithm_line = lines
+            .next()
+            .ok_or_else(|| missing_at_end(&input, "an enhancement algorithm"))?;
+        let algorithm_text = algorithm_line.trimmed();
+        let algorithm = algorithm_text
+            .char_indices()
+            .map(|(i, c)| {
+                Pixel::from_char(c).ok_or_else(|| {
+                    algorithm_line.unexpected(&algorithm_text[i..i + c.len_utf8()], "# or .")
+                })
+            })
+            .collect::<Result<Algorithm, ParseError>>()?;
+        if algorithm.len() != 512 {
+            return Err(ParseError::WrongLength {
+                line: algorithm_line.number,
+                expected: 512,
+                found: algorithm.len(),
+            });
+        }
+        let image = Grid::parse_lines(lines, "# or .", Pixel::from_char)?;
+        return Ok(Input { algorithm, image });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let image = enhance_image(input, 2);
+        return Ok(count_lit_pixels(&image));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let image = enhance_image(input, 50);
+        return Ok(count_lit_pixels(&image));
+    }
+}
+
+fn enhance_image(input: &Input, iteration_count: usize) -> Image {
+    fn get_pixel_around(img: &Image, x: isize, y: isize, empty: Pixel) -> Vec<Pixel> {
+        (y - 1..=y + 1)
+            .flat_map(|yi| (x - 1..=x + 1).map(move |xi| (xi, yi)))
+            .map(|(xi, yi)| *img.get_signed(xi, yi).unwrap_or(&empty))
+            .collect()
+    }
+
+    let algo = input.algorithm.clone();
+    let mut current_image = input.image.clone();
+    for iteration in 0..iteration_count {
+        let empty_pixel = if iteration % 2 == 0 {
+            Pixel::Dark
+        } else {
+            algo[0]
+        };
+        current_image = Grid::from_fn(
+            current_image.width() + 2,
+            current_image.height() + 2,
+            |i, j| {
+                let ii: isize = i.try_into().unwrap();
+                let ji: isize = j.try_into().unwrap();
+                let original_pixel_seq =
+                    get_pixel_around(&current_image, ii - 1, ji - 1, empty_pixel);
+                algo[pixel_vec_to_number(&original_pixel_seq)]
+            },
+        );
+    }
+    return current_image;
+}
+
+fn pixel_vec_to_number(pixels: &Vec<Pixel>) -> usize {
+    let binary: String = pixels
+        .iter()
+        .map(|p| match p {
+            Pixel::Light => "1",
+            Pixel::Dark => "0",
+        })
+        .collect::<Vec<&str>>()
+        .join("");
+    usize::from_str_radix(binary.as_str(), 2).expect("should be able to convert to number")
+}
+
+fn count_lit_pixels(image: &Image) -> usize {
+    image.values().filter(|pixel| pixel.is_light()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_input;
+
+    fn sample_input() -> Input {
+        let input = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+        
+        #..#.
+        #....
+        ##..#
+        ..#..
+        ..###";
+        Day20::parse_data(input.to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_convert_correctly_pixels_to_number() {
+        let input: Vec<Pixel> = vec![
+            Pixel::Dark,
+            Pixel::Dark,
+            Pixel::Dark,
+            Pixel::Light,
+            Pixel::Dark,
+            Pixel::Dark,
+            Pixel::Dark,
+            Pixel::Light,
+            Pixel::Dark,
+        ];
+        assert_eq!(pixel_vec_to_number(&input), 34);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 35;
+        assert_eq!(Day20::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly_with_test() {
+        let input = Day20::parse_data(read_input("input_test.txt").unwrap()).unwrap();
+        let expected = 5326;
+        assert_eq!(Day20::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 3351;
+        assert_eq!(Day20::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,189 @@
+use aoc_common::{missing_at_end, non_empty_lines, InputLine, ParseError, Solution};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Player {
+    position: u128,
+    score: u128,
+}
+
+impl Player {
+    fn parse_line(line: &InputLine) -> Result<Self, ParseError> {
+        // Player 1 starting position: 3
+        let (_, initial_position) = line.split_once(line.trimmed(), "position: ")?;
+        let position = line.parse_number(initial_position)?;
+        if !(1..=10).contains(&position) {
+            return Err(line.unexpected(initial_position, "a position between 1 and 10"));
+        }
+        Ok(Player { position, score: 0 })
+    }
+}
+
+impl std::str::FromStr for Player {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Player::parse_line(&InputLine { number: 1, text: s })
+    }
+}
+
+pub type Players = Vec<Player>;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Players;
+    type Part1Output = u128;
+    type Part2Output = u128;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let players = non_empty_lines(&input)
+            .map(|line| Player::parse_line(&line))
+            .collect::<Result<Players, ParseError>>()?;
+        if players.is_empty() {
+            return Err(missing_at_end(&input, "a starting position"));
+        }
+        return Ok(players);
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        let mut players = input.clone();
+        let mut current_player: usize = 0;
+        let mut dice_rolled: u128 = 0;
+        let mut last_dice: u128 = 0;
+        loop {
+            let (first, second, third) = next_deterministic_dices(last_dice);
+            last_dice = third;
+            dice_rolled += 3;
+
+            let player_position =
+                (players[current_player].position + first + second + third - 1) % 10 + 1;
+            players[current_player].position = player_position;
+            players[current_player].score += player_position;
+
+            if players[current_player].score >= 1000 {
+                break;
+            }
+            current_player = (current_player + 1) % players.len();
+        }
+        let loser_score = players.iter().map(|p| p.score).min().unwrap();
+        return Ok(loser_score * dice_rolled);
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        let initial_player = 0;
+        let wins = quantum_run(input.clone(), initial_player);
+        return Ok(*wins.iter().max().unwrap());
+    }
+}
+
+fn next_deterministic_dices(last_dice: u128) -> (u128, u128, u128) {
+    (
+        (last_dice % 1000) + 1,
+        (last_dice + 1) + 1,
+        (last_dice + 2) + 1,
+    )
+}
+
+lazy_static! {
+    static ref CACHE: RwLock<HashMap<(Players, usize), Vec<u128>>> = RwLock::new(HashMap::new());
+}
+
+fn quantum_run(players: Players, current_player: usize) -> Vec<u128> {
+    {
+        let cache = CACHE.read().unwrap();
+        if let Some(previous_res) = cache.get(&(players.clone(), current_player)) {
+            return previous_res.clone();
+        }
+    }
+    let dice_sets = next_quantum_dices();
+    let mut player_wins: Vec<u128> = vec![0; players.len()];
+    let next_player = (current_player + 1) % players.len();
+    for (first, second, third) in dice_sets.iter() {
+        let mut universe_player = players.clone();
+        let player_position =
+            (universe_player[current_player].position + first + second + third - 1) % 10 + 1;
+        universe_player[current_player].position = player_position;
+        universe_player[current_player].score += player_position;
+        if universe_player[current_player].score >= 21 {
+            player_wins[current_player] += 1;
+        } else {
+            let scores = quantum_run(universe_player, next_player);
+            scores.iter().enumerate().for_each(|(index, score)| {
+                player_wins[index] += score;
+            });
+        }
+    }
+    let mut cache = CACHE.write().unwrap();
+    cache.insert((players.clone(), current_player), player_wins.clone());
+    return player_wins;
+}
+
+fn next_quantum_dices() -> Vec<(u128, u128, u128)> {
+    let mut dices: Vec<(u128, u128, u128)> = Vec::new();
+    for x in 1..4 {
+        for y in 1..4 {
+            for z in 1..4 {
+                dices.push((x, y, z));
+            }
+        }
+    }
+    return dices;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Players {
+        vec![
+            Player {
+                position: 4,
+                score: 0,
+            },
+            Player {
+                position: 8,
+                score: 0,
+            },
+        ]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "Player 1 starting position: 4
+        Player 2 starting position: 8
+        ";
+        let expected = sample_input();
+        assert_eq!(Day21::parse_data(input.to_string()).unwrap(), expected);
+        let expected = ParseError::UnexpectedToken {
+            line: 1,
+            column: 29,
+            text: String::from("11"),
+            expected: String::from("a position between 1 and 10"),
+        };
+        let input = "Player 1 starting position: 11\n";
+        assert_eq!(Day21::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::MissingToken {
+            line: 1,
+            column: 1,
+            expected: String::from("a starting position"),
+        };
+        assert_eq!(Day21::parse_data(String::new()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 739785;
+        assert_eq!(Day21::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 444356092776315;
+        assert_eq!(Day21::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,554 @@
+use aoc_common::{input_lines, missing_at_end, InputLine, ParseError, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+
+/// Amphipods are stored as the index of their room, A being 0.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Burrow {
+    /// Hallway position in front of each side room, from left to right.
+    room_entrances: Vec<usize>,
+    hallway: Vec<Option<u8>>,
+    /// Side rooms from left to right, each from its entrance to its back.
+    rooms: Vec<Vec<Option<u8>>>,
+}
+
+impl Burrow {
+    fn is_win(&self) -> bool {
+        self.rooms
+            .iter()
+            .enumerate()
+            .all(|(room, cells)| cells.iter().all(|amphipod| *amphipod == Some(room as u8)))
+    }
+
+    /// Same burrow with the two lines folded in the diagram for part 2.
+    fn unfold(&self) -> Result<Burrow, String> {
+        let hidden = [[3, 2, 1, 0], [3, 1, 0, 2]];
+        if self.rooms.len() != 4 {
+            return Err(format!(
+                "Only a burrow of 4 side rooms can be unfolded, found {}",
+                self.rooms.len()
+            ));
+        }
+        let rooms = self
+            .rooms
+            .iter()
+            .enumerate()
+            .map(|(room, cells)| {
+                let mut unfolded = vec![cells[0]];
+                unfolded.extend(hidden.iter().map(|line| Some(line[room])));
+                unfolded.extend_from_slice(&cells[1..]);
+                unfolded
+            })
+            .collect();
+        return Ok(Burrow {
+            rooms,
+            ..self.clone()
+        });
+    }
+}
+
+/// The burrow drawn as in the puzzle, `.` being an empty cell.
+impl Display for Burrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.hallway.len() + 2;
+        let first = self.room_entrances.iter().min().map_or(0, |e| *e);
+        let last = self.room_entrances.iter().max().map_or(0, |e| *e);
+        let depth = self
+            .rooms
+            .iter()
+            .map(|cells| cells.len())
+            .max()
+            .unwrap_or(0);
+        let cell = |amphipod: &Option<u8>| amphipod.map_or('.', letter);
+
+        writeln!(f, "{}", "#".repeat(width))?;
+        let hallway: String = self.hallway.iter().map(cell).collect();
+        writeln!(f, "#{}#", hallway)?;
+        for row in 0..=depth {
+            let line: String = (0..width)
+                .map(|column| {
+                    let room = self
+                        .room_entrances
+                        .iter()
+                        .position(|entrance| entrance + 1 == column);
+                    match room.and_then(|room| self.rooms[room].get(row)) {
+                        Some(amphipod) => cell(amphipod),
+                        None if row == 0 || (first..=last + 2).contains(&column) => '#',
+                        None => ' ',
+                    }
+                })
+                .collect();
+            write!(f, "{}", line.trim_end())?;
+            if row < depth {
+                writeln!(f)?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Most side rooms of a burrow, so that the energy of its last amphipod type, 10^9 per step,
+/// leaves room for any total.
+const MAX_ROOMS: usize = 10;
+
+fn energy(amphipod: u8) -> u64 {
+    10u64.pow(amphipod as u32)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    type Part1Output = Option<u64>;
+    type Part2Output = Option<u64>;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        let lines = input_lines(&input).collect::<Vec<_>>();
+        let hallway_index = lines
+            .iter()
+            .position(|line| !line.trimmed().trim_matches('#').is_empty())
+            .ok_or_else(|| missing_at_end(&input, "a hallway"))?;
+        let hallway_cells = open_cells(&lines[hallway_index]);
+        let first_column = hallway_cells.first().map_or(0, |(column, _)| *column);
+
+        let mut rooms: Vec<(usize, Vec<(InputLine, &str)>)> = Vec::new();
+        for line in lines.iter().skip(hallway_index + 1) {
+            for (column, token) in open_cells(line) {
+                if !(first_column..first_column + hallway_cells.len()).contains(&column) {
+                    return Err(line.unexpected(token, "a wall"));
+                }
+                let entrance = column - first_column;
+                let all_rooms_found = rooms.len() == MAX_ROOMS;
+                match rooms.iter_mut().find(|(other, _)| *other == entrance) {
+                    Some((_, cells)) => cells.push((*line, token)),
+                    None if all_rooms_found => {
+                        let expected =
+                            format!("a wall, a burrow has at most {} side rooms", MAX_ROOMS);
+                        return Err(line.unexpected(token, &expected));
+                    }
+                    None if line.number == lines[hallway_index].number + 1 => {
+                        rooms.push((entrance, vec![(*line, token)]))
+                    }
+                    None => return Err(line.unexpected(token, "a wall")),
+                }
+            }
+            let found = open_cells(line).len();
+            if found != 0 && found != rooms.len() {
+                return Err(ParseError::WrongLength {
+                    line: line.number,
+                    expected: rooms.len(),
+                    found,
+                });
+            }
+        }
+        if rooms.is_empty() {
+            return Err(missing_at_end(&input, "a line of side rooms"));
+        }
+
+        let room_count = rooms.len();
+        let parse_cell = |line: &InputLine, token: &str| match token {
+            "." => Ok(None),
+            _ => parse_letter(line, token, room_count).map(Some),
+        };
+        let hallway = hallway_cells
+            .iter()
+            .map(|(_, token)| parse_cell(&lines[hallway_index], token))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        // each kind fills exactly one room, or the burrow can never be organized
+        let depth = rooms[0].1.len();
+        let room_cells = &rooms;
+        let mut counts = vec![0; room_count];
+        let in_reading_order = hallway_cells
+            .iter()
+            .map(|(_, token)| (lines[hallway_index], *token))
+            .chain((0..depth).flat_map(|row| room_cells.iter().map(move |(_, cells)| cells[row])));
+        for (line, token) in in_reading_order {
+            if let Some(amphipod) = parse_cell(&line, token)? {
+                counts[amphipod as usize] += 1;
+                if counts[amphipod as usize] > depth {
+                    let expected = format!("at most {} amphipods {}", depth, token);
+                    return Err(line.unexpected(token, &expected));
+                }
+            }
+        }
+        if let Some(amphipod) = counts.iter().position(|count| *count < depth) {
+            let expected = format!(
+                "{} amphipods {}, found {}",
+                depth,
+                letter(amphipod as u8),
+                counts[amphipod]
+            );
+            return Err(missing_at_end(&input, &expected));
+        }
+        let room_entrances = rooms.iter().map(|(entrance, _)| *entrance).collect();
+        let rooms = rooms
+            .iter()
+            .map(|(_, cells)| {
+                cells
+                    .iter()
+                    .map(|(line, token)| parse_cell(line, token))
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        return Ok(Burrow {
+            room_entrances,
+            hallway,
+            rooms,
+        });
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(best_path(input));
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        return Ok(best_path(&input.unfold()?));
+    }
+}
+
+/// Every cell of `line` an amphipod can stand on, with its column.
+fn open_cells<'a>(line: &InputLine<'a>) -> Vec<(usize, &'a str)> {
+    line.text
+        .char_indices()
+        .enumerate()
+        .filter(|(_, (_, c))| *c != '#' && !c.is_whitespace())
+        .map(|(column, (i, c))| (column, &line.text[i..i + c.len_utf8()]))
+        .collect()
+}
+
+fn parse_letter(line: &InputLine, l: &str, room_count: usize) -> Result<u8, ParseError> {
+    let last = (b'A' + room_count as u8 - 1) as char;
+    match l.chars().next() {
+        Some(c @ 'A'..='Z') if l.len() == 1 && c <= last => Ok(c as u8 - b'A'),
+        _ => Err(line.unexpected(l, &format!("an amphipod between A and {}", last))),
+    }
+}
+
+/// Least energy to organize the amphipods.
+fn best_path(start: &Burrow) -> Option<u64> {
+    let moves = best_moves(start)?;
+    return Some(moves.iter().map(|(energy, _)| energy).sum());
+}
+
+/// Burrow states along the cheapest way to organize the amphipods, starting with `start`, each
+/// with the energy spent by the move leading to it. Uses Dijkstra over burrow states.
+pub fn best_moves(start: &Burrow) -> Option<Vec<(u64, Burrow)>> {
+    let mut best_costs: HashMap<Burrow, u64> = HashMap::new();
+    let mut previous: HashMap<Burrow, (Burrow, u64)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    best_costs.insert(start.clone(), 0);
+    queue.push(Reverse((0, start.clone())));
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if state.is_win() {
+            let mut moves = Vec::new();
+            let mut current = state;
+            while let Some((before, move_cost)) = previous.remove(&current) {
+                moves.push((move_cost, current));
+                current = before;
+            }
+            moves.push((0, current));
+            moves.reverse();
+            return Some(moves);
+        }
+        if best_costs.get(&state).is_some_and(|best| *best < cost) {
+            continue;
+        }
+        for (move_cost, next_state) in next_moves(&state) {
+            let next_cost = cost + move_cost;
+            if best_costs
+                .get(&next_state)
+                .is_none_or(|best| next_cost < *best)
+            {
+                best_costs.insert(next_state.clone(), next_cost);
+                previous.insert(next_state.clone(), (state.clone(), move_cost));
+                queue.push(Reverse((next_cost, next_state)));
+            }
+        }
+    }
+    return None;
+}
+
+/// Every state of the best solution of `part` drawn as the puzzle diagram, with the energy
+/// spent by each move.
+pub fn replay(burrow: &Burrow, part: u8) -> Result<String, String> {
+    let start = match part {
+        2 => burrow.unfold()?,
+        _ => burrow.clone(),
+    };
+    let moves = best_moves(&start).ok_or("The amphipods cannot be organized")?;
+    let mut total = 0;
+    let mut steps = vec![format!("Start\n{}", start)];
+    for (i, window) in moves.windows(2).enumerate() {
+        let (energy, state) = &window[1];
+        total += energy;
+        let amphipod = moved_amphipod(&window[0].1, state).map_or('?', letter);
+        steps.push(format!(
+            "Move {}: {} uses {} energy (total {})\n{}",
+            i + 1,
+            amphipod,
+            energy,
+            total,
+            state
+        ));
+    }
+    return Ok(steps.join("\n\n"));
+}
+
+fn moved_amphipod(before: &Burrow, after: &Burrow) -> Option<u8> {
+    let cells = |burrow: &Burrow| {
+        burrow
+            .hallway
+            .iter()
+            .chain(burrow.rooms.iter().flatten())
+            .copied()
+            .collect::<Vec<_>>()
+    };
+    return cells(before)
+        .into_iter()
+        .zip(cells(after))
+        .find_map(|(old, new)| if old.is_none() { new } else { None });
+}
+
+fn letter(amphipod: u8) -> char {
+    (b'A' + amphipod) as char
+}
+
+fn is_hallway_clear(state: &Burrow, from: usize, to: usize) -> bool {
+    let (start, end) = if from < to { (from, to) } else { (to, from) };
+    (start..=end)
+        .filter(|position| *position != from)
+        .all(|position| state.hallway[position].is_none())
+}
+
+/// Every legal move with its energy: from a room to a hallway spot which is not in front of a
+/// room, or from the hallway to the back of its own room once only its kind is left there.
+fn next_moves(state: &Burrow) -> Vec<(u64, Burrow)> {
+    let mut moves: Vec<(u64, Burrow)> = Vec::new();
+    for (position, amphipod) in state
+        .hallway
+        .iter()
+        .enumerate()
+        .filter_map(|(position, a)| a.map(|a| (position, a)))
+    {
+        let room = amphipod as usize;
+        let cells = &state.rooms[room];
+        if cells.iter().any(|cell| cell.is_some_and(|a| a != amphipod))
+            || !is_hallway_clear(state, position, state.room_entrances[room])
+        {
+            continue;
+        }
+        let Some(depth) = cells.iter().rposition(|cell| cell.is_none()) else {
+            continue;
+        };
+        let mut next_burrow = state.clone();
+        next_burrow.hallway[position] = None;
+        next_burrow.rooms[room][depth] = Some(amphipod);
+        let steps = position.abs_diff(state.room_entrances[room]) + depth + 1;
+        moves.push((steps as u64 * energy(amphipod), next_burrow));
+    }
+    for (room, cells) in state.rooms.iter().enumerate() {
+        let Some(depth) = cells.iter().position(|cell| cell.is_some()) else {
+            continue;
+        };
+        if cells[depth..].iter().all(|cell| *cell == Some(room as u8)) {
+            continue;
+        }
+        let amphipod = cells[depth].unwrap();
+        for position in 0..state.hallway.len() {
+            if state.room_entrances.contains(&position)
+                || state.hallway[position].is_some()
+                || !is_hallway_clear(state, state.room_entrances[room], position)
+            {
+                continue;
+            }
+            let mut next_burrow = state.clone();
+            next_burrow.rooms[room][depth] = None;
+            next_burrow.hallway[position] = Some(amphipod);
+            let steps = depth + 1 + position.abs_diff(state.room_entrances[room]);
+            moves.push((steps as u64 * energy(amphipod), next_burrow));
+        }
+    }
+    return moves;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Burrow {
+        Burrow {
+            room_entrances: vec![2, 4, 6, 8],
+            hallway: vec![None; 11],
+            rooms: vec![
+                vec![Some(1), Some(0)],
+                vec![Some(2), Some(3)],
+                vec![Some(1), Some(2)],
+                vec![Some(3), Some(0)],
+            ],
+        }
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = "#############
+        #...........#
+        ###B#C#B#D###
+          #A#D#C#A#
+          #########";
+        let expected = sample_input();
+        assert_eq!(Day23::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_report_unknown_amphipod() {
+        let input = "#############\n#...........#\n###B#C#B#D###\n  #A#E#C#A#\n  #########";
+        let expected = ParseError::UnexpectedToken {
+            line: 4,
+            column: 6,
+            text: String::from("E"),
+            expected: String::from("an amphipod between A and D"),
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+        let expected = ParseError::MissingToken {
+            line: 2,
+            column: 14,
+            expected: String::from("a line of side rooms"),
+        };
+        assert_eq!(
+            Day23::parse_data(String::from("#############\n#...........#\n")),
+            Err(expected)
+        );
+    }
+
+    #[test]
+    fn it_should_report_unsolvable_burrow() {
+        let input = "#############\n#...........#\n###A#A#C#D###\n  #A#B#C#D#\n  #########";
+        let expected = ParseError::UnexpectedToken {
+            line: 4,
+            column: 4,
+            text: String::from("A"),
+            expected: String::from("at most 2 amphipods A"),
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+        let input = "#########\n#.......#\n###B#A###\n  #A#\n  ###";
+        let expected = ParseError::WrongLength {
+            line: 4,
+            expected: 2,
+            found: 1,
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+        let input = "#########\n#.......#\n###B#.###\n  #####";
+        let expected = ParseError::MissingToken {
+            line: 4,
+            column: 8,
+            expected: String::from("1 amphipods A, found 0"),
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_skip_moves_into_full_rooms() {
+        let burrow = Burrow {
+            room_entrances: vec![2, 4, 6, 8],
+            hallway: vec![None; 11],
+            rooms: vec![
+                vec![Some(0), Some(0)],
+                vec![Some(0), Some(1)],
+                vec![Some(2), Some(2)],
+                vec![Some(3), Some(3)],
+            ],
+        };
+        assert_eq!(Day23::part1(&burrow).unwrap(), None);
+    }
+
+    #[test]
+    fn it_should_unfold_burrow() {
+        let input = "#############
+        #...........#
+        ###B#C#B#D###
+          #D#C#B#A#
+          #D#B#A#C#
+          #A#D#C#A#
+          #########";
+        let expected = Day23::parse_data(input.to_string()).unwrap();
+        assert_eq!(sample_input().unfold(), Ok(expected));
+    }
+
+    #[test]
+    fn it_should_parse_custom_layout() {
+        let input = "#########\n#.B.....#\n###.#A###\n  #A#B#\n  #####\n";
+        let expected = Burrow {
+            room_entrances: vec![2, 4],
+            hallway: vec![None, Some(1), None, None, None, None, None],
+            rooms: vec![vec![None, Some(0)], vec![Some(0), Some(1)]],
+        };
+        assert_eq!(Day23::parse_data(input.to_string()).unwrap(), expected);
+        let input = "#########\n#.......#\n###B#D###\n";
+        let expected = ParseError::UnexpectedToken {
+            line: 3,
+            column: 6,
+            text: String::from("D"),
+            expected: String::from("an amphipod between A and B"),
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+        let input =
+            "#########################\n#.......................#\n###A#B#C#D#E#F#G#H#I#J#K###\n";
+        let expected = ParseError::UnexpectedToken {
+            line: 3,
+            column: 24,
+            text: String::from("K"),
+            expected: String::from("a wall, a burrow has at most 10 side rooms"),
+        };
+        assert_eq!(Day23::parse_data(input.to_string()), Err(expected));
+    }
+
+    #[test]
+    fn it_should_solve_custom_layout() {
+        let input = "#########\n#.......#\n###B#A###\n  #####\n";
+        let burrow = Day23::parse_data(input.to_string()).unwrap();
+        assert_eq!(Day23::part1(&burrow).unwrap(), Some(46));
+        assert!(Day23::part2(&burrow).is_err());
+    }
+
+    #[test]
+    fn it_should_render_burrow() {
+        let expected = "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########";
+        assert_eq!(sample_input().to_string(), expected);
+        let input = "#########\n#.B.....#\n###.#A###\n  #A#B#\n  #####";
+        assert_eq!(
+            Day23::parse_data(input.to_string()).unwrap().to_string(),
+            input
+        );
+    }
+
+    #[test]
+    fn it_should_replay_best_moves() {
+        let input = "#########\n#.......#\n###B#A###\n  #####\n";
+        let burrow = Day23::parse_data(input.to_string()).unwrap();
+        let moves = best_moves(&burrow).unwrap();
+        let energies: Vec<u64> = moves.iter().map(|(energy, _)| *energy).collect();
+        assert_eq!(energies, vec![0, 4, 20, 2, 20]);
+        assert!(moves.last().unwrap().1.is_win());
+        let replay = replay(&burrow, 1).unwrap();
+        assert!(replay.starts_with("Start\n#########\n#.......#\n###B#A###\n  #####\n\nMove 1: A uses 4 energy (total 4)\n"));
+        assert!(replay.ends_with(
+            "Move 4: B uses 20 energy (total 46)\n#########\n#.......#\n###A#B###\n  #####"
+        ));
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = Some(12521);
+        assert_eq!(Day23::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = Some(44169);
+        assert_eq!(Day23::part2(&input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_common::{non_empty_lines, ParseError, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    fn parse_data(input: String) -> Result<Self::Input, ParseError> {
+        return non_empty_lines(&input)
+            .map(|line| line.parse_number::<u32>(line.trimmed()))
+            .collect();
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1Output, String> {
+        return Ok(input.len());
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2Output, String> {
+        return Ok(input.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Vec<u32> {
+        vec![42]
+    }
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input = include_str!("../input_test.txt");
+        let expected = sample_input();
+        assert_eq!(Day::parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part1_correctly() {
+        let input = sample_input();
+        let expected = 1;
+        assert_eq!(Day::part1(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_compute_part2_correctly() {
+        let input = sample_input();
+        let expected = 1;
+        assert_eq!(Day::part2(&input).unwrap(), expected);
+    }
+}